@@ -0,0 +1,53 @@
+//  O Registro de main.rs, agora com #[derive(BstKey)] no lugar dos
+//  PartialEq e PartialOrd escritos à mão
+//      cargo run --example registro_derive --features derive
+
+use std::fmt::{self, Display};
+
+use bst::{BstKey, BST};
+
+#[derive(BstKey, Debug)]
+struct Registro {
+    #[key]
+    id: i32,
+    nome: String,
+    idade: i32,
+}
+
+impl Registro {
+    fn new(id: i32, nome: &str, idade: i32) -> Registro {
+        Registro { id, nome: nome.to_string(), idade }
+    }
+}
+
+impl Display for Registro {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ID: {}", self.id)
+    }
+}
+
+fn main() {
+    let mut bst: BST<Registro> = BST::new();
+
+    bst.insert(Registro::new(16, "Alex", 18));
+    bst.insert(Registro::new(8, "Ana", 15));
+    bst.insert(Registro::new(24, "Bruno", 21));
+    bst.insert(Registro::new(4, "Maria", 17));
+    bst.insert(Registro::new(12, "Lucas", 28));
+
+    println!("Vizualização em árvore:");
+    bst.show_tree();
+
+    //  busca direto pelo id, sem Registro::with_id
+    if let Some(registro) = bst.get_by_key(&12) {
+        println!("\nID 12: {} ({} anos)", registro.nome, registro.idade);
+    }
+
+    if let Some(idade) = bst.get_by_key_mut(&4).map(|r| &mut r.idade) {
+        *idade += 1;
+    }
+
+    println!("Removendo o ID 8: {:?}", bst.remove_by_key(&8));
+    println!("\nEm ordem crescente:");
+    println!("{:#?}", bst.walk());
+}
@@ -0,0 +1,80 @@
+//  Undo/redo com a PersistentBst
+//  Cada edição gera uma nova versão; guardar todas elas é barato,
+//  pois as versões compartilham as sub-árvores que não mudaram.
+//      cargo run --example versions
+
+use bst::PersistentBst;
+
+struct Editor {
+    versions: Vec<PersistentBst<i32>>,
+    //  índice da versão visível, versões depois dele são o "redo"
+    current: usize,
+}
+
+impl Editor {
+    fn new() -> Editor {
+        Editor { versions: vec![PersistentBst::new()], current: 0 }
+    }
+
+    fn tree(&self) -> &PersistentBst<i32> {
+        &self.versions[self.current]
+    }
+
+    fn apply(&mut self, next: PersistentBst<i32>) {
+        //  uma nova edição descarta o histórico de redo
+        self.versions.truncate(self.current + 1);
+        self.versions.push(next);
+        self.current += 1;
+    }
+
+    fn insert(&mut self, value: i32) {
+        let next = self.tree().insert(value);
+        self.apply(next);
+    }
+
+    fn delete(&mut self, value: i32) {
+        let next = self.tree().delete(&value);
+        self.apply(next);
+    }
+
+    fn undo(&mut self) {
+        self.current = self.current.saturating_sub(1);
+    }
+
+    fn redo(&mut self) {
+        if self.current + 1 < self.versions.len() {
+            self.current += 1;
+        }
+    }
+}
+
+fn main() {
+    let mut editor = Editor::new();
+
+    for value in [50, 30, 70, 20, 40, 60, 80] {
+        editor.insert(value);
+    }
+    println!("Versão {}: {:?}", editor.current, editor.tree().walk());
+    editor.tree().show_tree();
+
+    editor.delete(30);
+    editor.insert(65);
+    println!("\nVersão {}: {:?}", editor.current, editor.tree().walk());
+    editor.tree().show_tree();
+
+    editor.undo();
+    editor.undo();
+    println!("\nDepois de 2 undos, versão {}: {:?}", editor.current, editor.tree().walk());
+
+    editor.redo();
+    println!("Depois de 1 redo, versão {}: {:?}", editor.current, editor.tree().walk());
+
+    //  Um snapshot é só um clone da root
+    let snapshot = editor.tree().clone();
+    editor.insert(10);
+    println!("\nSnapshot: {:?}", snapshot.walk());
+    println!("Atual:    {:?}", editor.tree().walk());
+    println!("Mesma árvore? {}", snapshot.ptr_eq(editor.tree()));
+
+    println!("\n{} versões guardadas", editor.versions.len());
+}
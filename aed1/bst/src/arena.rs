@@ -0,0 +1,539 @@
+/*
+ *  Backend alternativo da BST, sem nenhum bloco unsafe.
+ *  Todos os nodes ficam em um único Vec (a "arena") e os
+ *  ponteiros NonNull são trocados por índices u32 nesse Vec.
+ *  Na remoção o último node do Vec ocupa a posição do removido
+ *  (swap_remove), assim a arena nunca tem buracos e não é
+ *  necessária uma lista de posições livres.
+ *  Habilitado pela feature "arena", com a mesma interface
+ *  pública do backend com ponteiros.
+*/
+
+use std::{cmp::{self, Ordering}, fmt::Display};
+
+use crate::{observer::{self, BstObserver, Direction}, show};
+
+/// Índice de um node dentro da arena
+pub(crate) type Link = Option<u32>;
+
+pub struct Node<T: PartialOrd> {
+    pub(crate) value: T,
+    pub(crate) parent: Link,
+    pub(crate) left: Link,
+    pub(crate) right: Link,
+}
+
+impl <T: PartialOrd> Node<T> {
+    pub fn new(value: T) -> Node<T> {
+        Node {
+            value,
+            parent: None,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Uma leaf é um node sem filhos
+    pub fn is_leaf(&self) -> bool {
+        return matches!((self.left, self.right), (None, None));
+    }
+}
+
+pub struct BST<T: PartialOrd> {
+    pub(crate) nodes: Vec<Node<T>>,
+    pub(crate) root: Link,
+}
+
+impl<T: PartialOrd> Default for BST<T> {
+    fn default() -> Self {
+        BST::new()
+    }
+}
+
+//  Implementação da interface pública
+impl <T: PartialOrd> BST<T> {
+
+    /// Retorna uma nova instância de BST
+    pub fn new() -> BST<T> {
+        BST { nodes: Vec::new(), root: None }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn search(&self, value: &T) -> bool {
+        self.get(value).is_some()
+    }
+
+    ///  Adiciona um elemento à BST
+    ///  **false** se o elemento já existia
+    ///  **true** se o elemento foi adicionado <br>
+    pub fn insert(&mut self, value: T) -> bool {
+        self.insert_traced(value, &mut ())
+    }
+
+    /// Remove um elemento da BST <Br>
+    /// **false** elemento não encontrado <br>
+    /// **true** elemento encontrado e deletado
+    pub fn delete(&mut self, value: &T) -> bool {
+        self.delete_traced(value, &mut ())
+    }
+
+    /// insert() avisando observer de cada passo
+    pub fn insert_traced(&mut self, value: T, observer: &mut impl BstObserver<T>) -> bool {
+
+        let Some(mut current) = self.root else {
+            observer.link(&value, None);
+            self.root = Some(self.push_node(Node::new(value)));
+            return true;
+        };
+
+        //  desce até achar a posição vazia onde o valor deve ficar
+        let direction = loop {
+            let node = &self.nodes[current as usize];
+            let ordering = observer::ordering(&value, &node.value);
+            observer.compare(&value, &node.value, ordering);
+
+            let (next, direction) = match ordering {
+                Ordering::Less => (node.left, Direction::Left),
+                Ordering::Greater => (node.right, Direction::Right),
+                Ordering::Equal => return false,
+            };
+
+            match next {
+                Some(next) => {
+                    observer.descend(&node.value, direction);
+                    current = next;
+                }
+                None => break direction,
+            }
+        };
+
+        observer.link(&value, Some((&self.nodes[current as usize].value, direction)));
+        let mut node = Node::new(value);
+        node.parent = Some(current);
+        let index = self.push_node(node);
+
+        let parent = &mut self.nodes[current as usize];
+        match direction {
+            Direction::Left => parent.left = Some(index),
+            Direction::Right => parent.right = Some(index),
+        }
+
+        return true;
+    }
+
+    /// delete() avisando observer de cada passo
+    pub fn delete_traced(&mut self, value: &T, observer: &mut impl BstObserver<T>) -> bool {
+
+        let Some(node) = self.find_node_observed(value, observer) else {
+            return false; //    não há node para deletar
+        };
+        self.delete_node_observed(node, observer);
+
+        return true;
+    }
+
+    pub fn get(&self, value: &T) -> Option<&T> {
+        self.get_node(value).map(|n| &self.nodes[n as usize].value)
+    }
+
+    pub fn minimum(&self) -> Option<&T> {
+        let minimum = self.minimum_helper(self.root?);
+        return Some(&self.nodes[minimum as usize].value);
+    }
+
+    pub fn maximum(&self) -> Option<&T> {
+        let maximum = self.maximum_helper(self.root?);
+        return Some(&self.nodes[maximum as usize].value);
+    }
+
+    pub fn total_len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        self.is_balanced_recursive(self.root)
+    }
+
+    pub fn is_bst(&self) -> bool {
+        let Some(root) = self.root else {return true;};
+        self.is_bst_recursive(root)
+    }
+
+    pub fn leafs_len(&self) -> usize {
+        //  a arena não tem buracos, basta olhar todos os nodes
+        self.nodes.iter().filter(|n| n.is_leaf()).count()
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.root = None;
+    }
+
+    pub fn altura(&self) -> usize {
+        self.altura_recursiva(self.root)
+    }
+
+    //  walk in order
+    pub fn walk(&self) -> Vec<&T> {
+        let mut vec: Vec<&T> = Vec::with_capacity(self.nodes.len());
+        self.walk_recursive(self.root, &mut vec);
+        return vec;
+    }
+
+    pub fn walk_reverse(&self) -> Vec<&T> {
+        let mut vec = Vec::with_capacity(self.nodes.len());
+        self.walk_reverse_recursive(self.root, &mut vec);
+        vec
+    }
+}
+
+//  Interface privada
+impl<T: PartialOrd> BST<T> {
+
+    pub(crate) fn node(&self, index: u32) -> &Node<T> {
+        &self.nodes[index as usize]
+    }
+
+    fn node_mut(&mut self, index: u32) -> &mut Node<T> {
+        &mut self.nodes[index as usize]
+    }
+
+    //  Adiciona o node ao fim da arena e retorna seu índice
+    fn push_node(&mut self, node: Node<T>) -> u32 {
+        let index = u32::try_from(self.nodes.len()).expect("arena cheia: mais de u32::MAX nodes");
+        self.nodes.push(node);
+        index
+    }
+
+    //  Retorna o índice do node com o valor, se houver
+    pub(crate) fn get_node(&self, value: &T) -> Link {
+        let mut current = self.root;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+
+            if value < &node.value {
+                current = node.left;
+            }
+            else if value > &node.value {
+                current = node.right;
+            }
+            else {
+                return Some(index);
+            }
+        }
+
+        return None;
+    }
+
+    //  Retorna o índice do menor node da sub-árvore
+    //  esse node pode ser a própria root, se não houver filhos
+    pub(crate) fn minimum_helper(&self, mut node: u32) -> u32 {
+        while let Some(left) = self.node(node).left {
+            node = left;
+        }
+        return node;
+    }
+
+    //  Retorna o índice do maior node da sub-árvore
+    //  esse node pode ser a própria root, se não houver filhos
+    pub(crate) fn maximum_helper(&self, mut node: u32) -> u32 {
+        while let Some(right) = self.node(node).right {
+            node = right;
+        }
+        return node;
+    }
+
+    //  Retorna o índice do sucessor de um Node na bst.
+    //  Pode ser que não haja um sucessor (None)
+    pub(crate) fn sucessor(&self, node: u32) -> Link {
+        if let Some(right) = self.node(node).right {
+            return Some(self.minimum_helper(right));
+        }
+
+        //  sobe enquanto o node atual for o filho direito do seu parent
+        let mut current = node;
+        let mut parent = self.node(node).parent;
+
+        while let Some(p) = parent {
+            if Some(current) != self.node(p).right {break;}
+            current = p;
+            parent = self.node(p).parent;
+        }
+        return parent;
+    }
+
+    //  Retorna o índice do antecessor de um Node na bst,
+    //  simétrico ao sucessor.
+    //  Pode ser que não haja um antecessor (None)
+    pub(crate) fn antecessor(&self, node: u32) -> Link {
+        if let Some(left) = self.node(node).left {
+            return Some(self.maximum_helper(left));
+        }
+
+        //  sobe enquanto o node atual for o filho esquerdo do seu parent
+        let mut current = node;
+        let mut parent = self.node(node).parent;
+
+        while let Some(p) = parent {
+            if Some(current) != self.node(p).left {break;}
+            current = p;
+            parent = self.node(p).parent;
+        }
+        return parent;
+    }
+
+    //  Coloca n2 (e sua subarvore) no lugar de n1 (e sua subarvore) na árvore
+    //  n1 é removido (mas não deletado) da árvore
+    fn transplant(&mut self, n1: u32, n2: Link) {
+
+        //  não há nada o que trocar, ambos são o mesmo node
+        if Some(n1) == n2 {return;};
+
+        let n1_parent = self.node(n1).parent;
+        match n1_parent {
+            None => self.root = n2,
+            Some(parent) => {
+                let parent = self.node_mut(parent);
+                if parent.left == Some(n1) {
+                    parent.left = n2;
+                }
+                else {
+                    parent.right = n2;
+                }
+            }
+        }
+
+        //  Se houver um n2, seu campo parent aponta para o parent de n1
+        if let Some(n2) = n2 {
+            self.node_mut(n2).parent = n1_parent;
+        }
+    }
+
+    //  get_node() avisando observer de cada passo
+    fn find_node_observed(&self, value: &T, observer: &mut impl BstObserver<T>) -> Link {
+        let mut current = self.root;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+            let ordering = observer::ordering(value, &node.value);
+            observer.compare(value, &node.value, ordering);
+
+            let (next, direction) = match ordering {
+                Ordering::Less => (node.left, Direction::Left),
+                Ordering::Greater => (node.right, Direction::Right),
+                Ordering::Equal => return Some(index),
+            };
+            if next.is_some() {
+                observer.descend(&node.value, direction);
+            }
+            current = next;
+        }
+
+        return None;
+    }
+
+    //  Remove o node da árvore e devolve o valor que ele guardava
+    //  O último node da arena passa a ocupar o índice removido
+    pub(crate) fn delete_node(&mut self, node: u32) -> T {
+        self.delete_node_observed(node, &mut ())
+    }
+
+    fn delete_node_observed(&mut self, node: u32, observer: &mut impl BstObserver<T>) -> T {
+        let left = self.node_mut(node).left.take();
+        let right = self.node_mut(node).right.take();
+
+        match (left, right) {
+            (None, None) => self.transplant_observed(node, None, observer),
+            (None, Some(right)) => self.transplant_observed(node, Some(right), observer),
+            (Some(left), None) => self.transplant_observed(node, Some(left), observer),
+            (Some(left), Some(right)) => {
+                let sucessor = self.minimum_helper(right);
+                observer.sucessor(&self.node(node).value, &self.node(sucessor).value);
+
+                //  Se o sucessor não está diretamente a direita do node, são necessários 2 transplantes
+                if self.node(sucessor).parent != Some(node) {
+                    //  sucessor é substituido por seu nó a direita na árvore
+                    let sucessor_right = self.node(sucessor).right;
+                    self.transplant_observed(sucessor, sucessor_right, observer);
+
+                    //  sucessor.right é ligado (e vice-versa) ao filho direiro de node
+                    self.node_mut(right).parent = Some(sucessor);
+                    self.node_mut(sucessor).right = Some(right);
+                }
+
+                //  sucessor.left é ligado (e vice-versa) ao filho esquerdo do node
+                self.node_mut(left).parent = Some(sucessor);
+                self.node_mut(sucessor).left = Some(left);
+
+                //  node é trocado por sucessor
+                self.transplant_observed(node, Some(sucessor), observer);
+            }
+        }
+
+        //  o node está fora da árvore, falta tirá-lo da arena
+        let last = (self.nodes.len() - 1) as u32;
+        if node != last {
+            self.relink(last, node);
+        }
+        return self.nodes.swap_remove(node as usize).value;
+    }
+
+    fn transplant_observed(&mut self, n1: u32, n2: Link, observer: &mut impl BstObserver<T>) {
+        observer.transplant(&self.node(n1).value, n2.map(|n| &self.node(n).value));
+        self.transplant(n1, n2);
+    }
+
+    fn relink(&mut self, from: u32, to: u32) {
+        let node = self.node(from);
+        let (parent, left, right) = (node.parent, node.left, node.right);
+
+        match parent {
+            None => self.root = Some(to),
+            Some(parent) => {
+                let parent = self.node_mut(parent);
+                if parent.left == Some(from) {
+                    parent.left = Some(to);
+                }
+                else {
+                    parent.right = Some(to);
+                }
+            }
+        }
+
+        if let Some(left) = left {
+            self.node_mut(left).parent = Some(to);
+        }
+        if let Some(right) = right {
+            self.node_mut(right).parent = Some(to);
+        }
+    }
+
+    fn walk_recursive<'a>(&'a self, node: Link, vec: &mut Vec<&'a T>) {
+        if let Some(node) = node {
+            let node = self.node(node);
+            self.walk_recursive(node.left, vec);
+            vec.push(&node.value);
+            self.walk_recursive(node.right, vec);
+        }
+    }
+
+    //  é mais eficiente fazer outra função do que usar reverse no resultado da padrão
+    fn walk_reverse_recursive<'a>(&'a self, node: Link, vec: &mut Vec<&'a T>) {
+        if let Some(node) = node {
+            let node = self.node(node);
+            self.walk_reverse_recursive(node.right, vec);
+            vec.push(&node.value);
+            self.walk_reverse_recursive(node.left, vec);
+        }
+    }
+
+    fn altura_recursiva(&self, node: Link) -> usize {
+        let Some(node) = node else {return 0;};
+        let node = self.node(node);
+
+        let left_height = self.altura_recursiva(node.left);
+        let right_height = self.altura_recursiva(node.right);
+
+        return cmp::max(left_height, right_height) + 1;
+    }
+
+    fn is_balanced_recursive(&self, node: Link) -> bool {
+        let Some(node) = node else {return true;};
+        let node = self.node(node);
+
+        let left_ok = self.is_balanced_recursive(node.left);
+        let right_ok = self.is_balanced_recursive(node.right);
+        let height_balanced =
+            (self.altura_recursiva(node.left) as i32 -
+            self.altura_recursiva(node.right) as i32).abs() <= 1;
+
+        return left_ok && right_ok && height_balanced;
+    }
+
+    fn is_bst_recursive(&self, node: u32) -> bool {
+        let node = self.node(node);
+
+        if let Some(left) = node.left {
+            if self.node(left).value > node.value {return false}
+            if !self.is_bst_recursive(left) {return false}
+        }
+
+        if let Some(right) = node.right {
+            if self.node(right).value < node.value {return false}
+            return self.is_bst_recursive(right);
+        }
+
+        return true;
+    }
+}
+
+//  Buscas guiadas por uma função de comparação
+//  f(valor) compara o alvo com o valor do node, como em alvo.cmp(valor),
+//  permitindo buscar por apenas uma parte do valor (uma chave, por exemplo)
+impl<T: PartialOrd> BST<T> {
+    fn get_node_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Link {
+        let mut current = self.root;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+
+            match f(&node.value) {
+                Ordering::Less => current = node.left,
+                Ordering::Greater => current = node.right,
+                Ordering::Equal => return Some(index),
+            }
+        }
+
+        return None;
+    }
+
+    //  Primeiro node cujo valor não é menor que o alvo
+    pub(crate) fn lower_bound_node_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Link {
+        let mut current = self.root;
+        let mut candidate = None;
+
+        while let Some(index) = current {
+            let node = self.node(index);
+
+            if f(&node.value) == Ordering::Greater {
+                current = node.right;
+            } else {
+                candidate = Some(index);
+                current = node.left;
+            }
+        }
+
+        return candidate;
+    }
+
+    pub(crate) fn get_by(&self, f: impl FnMut(&T) -> Ordering) -> Option<&T> {
+        self.get_node_by(f).map(|n| &self.node(n).value)
+    }
+
+    //  Cuidado: alterar a parte do valor usada na comparação quebra a BST
+    pub(crate) fn get_mut_by(&mut self, f: impl FnMut(&T) -> Ordering) -> Option<&mut T> {
+        self.get_node_by(f).map(|n| &mut self.node_mut(n).value)
+    }
+
+    pub(crate) fn remove_by(&mut self, f: impl FnMut(&T) -> Ordering) -> Option<T> {
+        let node = self.get_node_by(f)?;
+        return Some(self.delete_node(node));
+    }
+}
+
+//  Print da árvore para Ts que implementam Display
+//  (Ts que podem ser printados)
+//  Mesmo formato do backend com ponteiros
+impl<T: Display + PartialOrd> BST<T> {
+    /// Método público para iniciar a impressão visual da árvore.
+    pub fn show_tree(&self) {
+        show::show_tree(self.root, |index| {
+            let node = self.node(index);
+            (&node.value, node.left, node.right)
+        });
+    }
+}
@@ -0,0 +1,228 @@
+/*
+ *  Cursores do backend "arena", com a mesma interface de cursor.rs.
+ *  Aqui o cursor guarda apenas o índice do node atual.
+*/
+
+use std::cmp::Ordering;
+
+use crate::arena::{Link, BST};
+
+/// Cursor somente leitura sobre uma BST
+pub struct Cursor<'a, T: PartialOrd> {
+    tree: &'a BST<T>,
+    node: Link,
+}
+
+/// Cursor capaz de remover elementos da BST
+pub struct CursorMut<'a, T: PartialOrd> {
+    tree: &'a mut BST<T>,
+    node: Link,
+}
+
+//  Implementado manualmente pois o derive exigiria T: Clone
+impl<T: PartialOrd> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialOrd> Copy for Cursor<'_, T> {}
+
+impl<'a, T: PartialOrd> Cursor<'a, T> {
+    pub(crate) fn new(tree: &'a BST<T>, node: Link) -> Cursor<'a, T> {
+        Cursor { tree, node }
+    }
+
+    //  Cursor na mesma árvore, apontando para outro node
+    fn at(&self, node: Link) -> Option<Cursor<'a, T>> {
+        node.map(|n| Cursor::new(self.tree, Some(n)))
+    }
+
+    /// Valor do node atual <br>
+    /// **None** se o cursor está fora da árvore
+    pub fn value(&self) -> Option<&'a T> {
+        self.node.map(|n| &self.tree.node(n).value)
+    }
+
+    /// Cursor para o filho esquerdo, se houver
+    pub fn left(&self) -> Option<Cursor<'a, T>> {
+        self.at(self.tree.node(self.node?).left)
+    }
+
+    /// Cursor para o filho direito, se houver
+    pub fn right(&self) -> Option<Cursor<'a, T>> {
+        self.at(self.tree.node(self.node?).right)
+    }
+
+    /// Cursor para o parent, se houver (a root não tem parent)
+    pub fn parent(&self) -> Option<Cursor<'a, T>> {
+        self.at(self.tree.node(self.node?).parent)
+    }
+
+    /// Distância até a root (a root tem profundidade 0)
+    pub fn depth(&self) -> Option<usize> {
+        Some(depth(self.tree, self.node?))
+    }
+
+    /// Avança para o próximo elemento em ordem crescente
+    pub fn move_next(&mut self) {
+        self.node = match self.node {
+            Some(node) => self.tree.sucessor(node),
+            None => self.tree.root.map(|r| self.tree.minimum_helper(r)),
+        };
+    }
+
+    /// Retrocede para o elemento anterior em ordem crescente
+    pub fn move_prev(&mut self) {
+        self.node = match self.node {
+            Some(node) => self.tree.antecessor(node),
+            None => self.tree.root.map(|r| self.tree.maximum_helper(r)),
+        };
+    }
+}
+
+impl<'a, T: PartialOrd> CursorMut<'a, T> {
+    pub(crate) fn new(tree: &'a mut BST<T>, node: Link) -> CursorMut<'a, T> {
+        CursorMut { tree, node }
+    }
+
+    /// Cursor somente leitura na mesma posição
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor::new(self.tree, self.node)
+    }
+
+    /// Valor do node atual <br>
+    /// **None** se o cursor está fora da árvore
+    pub fn value(&self) -> Option<&T> {
+        self.node.map(|n| &self.tree.node(n).value)
+    }
+
+    /// Distância até a root (a root tem profundidade 0)
+    pub fn depth(&self) -> Option<usize> {
+        Some(depth(self.tree, self.node?))
+    }
+
+    /// Move para o filho esquerdo <br>
+    /// **false** se não há filho esquerdo (o cursor não se move)
+    pub fn move_left(&mut self) -> bool {
+        self.move_to(|tree, n| tree.node(n).left)
+    }
+
+    /// Move para o filho direito <br>
+    /// **false** se não há filho direito (o cursor não se move)
+    pub fn move_right(&mut self) -> bool {
+        self.move_to(|tree, n| tree.node(n).right)
+    }
+
+    /// Move para o parent <br>
+    /// **false** se o cursor está na root (o cursor não se move)
+    pub fn move_parent(&mut self) -> bool {
+        self.move_to(|tree, n| tree.node(n).parent)
+    }
+
+    /// Avança para o próximo elemento em ordem crescente
+    pub fn move_next(&mut self) {
+        self.node = match self.node {
+            Some(node) => self.tree.sucessor(node),
+            None => self.tree.root.map(|r| self.tree.minimum_helper(r)),
+        };
+    }
+
+    /// Retrocede para o elemento anterior em ordem crescente
+    pub fn move_prev(&mut self) {
+        self.node = match self.node {
+            Some(node) => self.tree.antecessor(node),
+            None => self.tree.root.map(|r| self.tree.maximum_helper(r)),
+        };
+    }
+
+    /// Remove o elemento atual e devolve seu valor <br>
+    /// O cursor passa a apontar para o sucessor do elemento removido
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.node?;
+        let sucessor = self.tree.sucessor(node);
+
+        //  a remoção move o último node da arena para o índice removido
+        let last = (self.tree.nodes.len() - 1) as u32;
+        self.node = if sucessor == Some(last) { Some(node) } else { sucessor };
+
+        return Some(self.tree.delete_node(node));
+    }
+
+    fn move_to(&mut self, f: impl Fn(&BST<T>, u32) -> Link) -> bool {
+        let Some(node) = self.node else {return false;};
+
+        match f(self.tree, node) {
+            Some(next) => {
+                self.node = Some(next);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn depth<T: PartialOrd>(tree: &BST<T>, mut node: u32) -> usize {
+    let mut depth = 0;
+
+    while let Some(parent) = tree.node(node).parent {
+        node = parent;
+        depth += 1;
+    }
+
+    depth
+}
+
+//  Construtores de cursores
+impl<T: PartialOrd> BST<T> {
+    /// Cursor na root da árvore
+    pub fn cursor_root(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.root)
+    }
+
+    /// Cursor no menor elemento
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.root.map(|r| self.minimum_helper(r)))
+    }
+
+    /// Cursor no maior elemento
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor::new(self, self.root.map(|r| self.maximum_helper(r)))
+    }
+
+    /// Cursor no elemento igual a value <br>
+    /// O cursor fica fora da árvore se value não for encontrado
+    pub fn cursor_at(&self, value: &T) -> Cursor<'_, T> {
+        Cursor::new(self, self.get_node(value))
+    }
+
+    //  Cursor no primeiro elemento que não é menor que o alvo de f
+    //  (veja get_by), fora da árvore se não houver
+    pub(crate) fn cursor_lower_bound_by(&self, f: impl FnMut(&T) -> Ordering) -> Cursor<'_, T> {
+        Cursor::new(self, self.lower_bound_node_by(f))
+    }
+
+    /// Versão mutável de cursor_root()
+    pub fn cursor_root_mut(&mut self) -> CursorMut<'_, T> {
+        let root = self.root;
+        CursorMut::new(self, root)
+    }
+
+    /// Versão mutável de cursor_front()
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let front = self.root.map(|r| self.minimum_helper(r));
+        CursorMut::new(self, front)
+    }
+
+    /// Versão mutável de cursor_back()
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let back = self.root.map(|r| self.maximum_helper(r));
+        CursorMut::new(self, back)
+    }
+
+    /// Versão mutável de cursor_at()
+    pub fn cursor_at_mut(&mut self, value: &T) -> CursorMut<'_, T> {
+        let node = self.get_node(value);
+        CursorMut::new(self, node)
+    }
+}
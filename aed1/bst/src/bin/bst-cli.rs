@@ -0,0 +1,322 @@
+/*
+ *  REPL para experimentar com a BST sem recompilar:
+ *      cargo run --bin bst-cli [arquivo]
+ *
+ *  A árvore guarda inteiros ou compras (nome, quantidade e preço,
+ *  ordenadas pelo nome). Cada alteração cria uma nova versão de uma
+ *  PersistentBst, então undo apenas volta para a versão anterior.
+ *
+ *  Arquivos aceitos pelo load:
+ *      - BST de inteiros no formato binário (binary_format.rs)
+ *      - compras em texto "nome qtd preço", como ORI/atv1/compra.txt (.txt)
+ *      - compras no formato de ORI/atv2/compras.bin (qualquer outro)
+ *  O save usa o mesmo formato do tipo da árvore atual.
+*/
+
+#![allow(clippy::needless_return)]
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    fs::{self, File},
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use bst::{binary_format::MAGIC, records, records::Purchase, text_records, PersistentBst, BST};
+
+const AJUDA: &str = "\
+comandos:
+    insert <valor>...       insere inteiros, ou compras como \"nome qtd preço\"
+    delete <chave>          remove pelo valor (inteiros) ou pelo nome (compras)
+    find <chave>            procura pelo valor ou pelo nome
+    min | max               menor e maior elemento
+    range <de> <até>        elementos entre as duas chaves, inclusive
+    show                    desenha a árvore
+    stats                   altura, folhas, balanceamento...
+    load <arquivo>          carrega inteiros (formato binário) ou compras (.txt ou .bin)
+    save <arquivo>          salva a árvore atual
+    mode <ints|compras>     começa uma árvore vazia do tipo escolhido
+    clear                   esvazia a árvore
+    undo                    desfaz a última alteração
+    help                    mostra esta ajuda
+    quit                    sai";
+
+/// Compra guardada na árvore, comparada apenas pelo nome
+#[derive(Clone)]
+struct Compra(Purchase);
+
+impl PartialEq for Compra {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.name == other.0.name
+    }
+}
+
+impl PartialOrd for Compra {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.name.partial_cmp(&other.0.name)
+    }
+}
+
+impl Display for Compra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} x {:.2})", self.0.name, self.0.qty, self.0.price)
+    }
+}
+
+/// O que a árvore pode guardar
+trait Value: PartialOrd + Clone + Display + Sized {
+    /// Valores do comando insert <br>
+    /// line_number é a linha da entrada, usada nas mensagens de erro
+    fn parse(args: &[&str], line_number: usize) -> Result<Vec<Self>, String>;
+
+    /// Valor usado para procurar pela chave (como o Registro::with_id de main.rs)
+    fn probe(key: &str) -> Result<Self, String>;
+
+    fn save(tree: &PersistentBst<Self>, path: &Path) -> Result<(), String>;
+}
+
+impl Value for i64 {
+    fn parse(args: &[&str], _line_number: usize) -> Result<Vec<i64>, String> {
+        args.iter().map(|arg| i64::probe(arg)).collect()
+    }
+
+    fn probe(key: &str) -> Result<i64, String> {
+        key.parse().map_err(|_| format!("inteiro inválido: {:?}", key))
+    }
+
+    //  formato binário, que preserva o formato da árvore
+    fn save(tree: &PersistentBst<i64>, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        BST::from(tree).write_to(file).map_err(|e| e.to_string())
+    }
+}
+
+impl Value for Compra {
+    fn parse(args: &[&str], line_number: usize) -> Result<Vec<Compra>, String> {
+        if !args.len().is_multiple_of(3) {
+            return Err("compras são inseridas como \"nome qtd preço\"".to_string());
+        }
+
+        let mut compras = Vec::new();
+        for fields in args.chunks(3) {
+            let purchase = text_records::parse_line(&fields.join(" "), line_number).map_err(|e| e.to_string())?;
+            compras.extend(purchase.map(Compra));
+        }
+        return Ok(compras);
+    }
+
+    fn probe(key: &str) -> Result<Compra, String> {
+        Ok(Compra(Purchase::new(key, 0, 0.0)))
+    }
+
+    fn save(tree: &PersistentBst<Compra>, path: &Path) -> Result<(), String> {
+        let compras: Vec<&Purchase> = tree.walk().into_iter().map(|c| &c.0).collect();
+        let mut file = io::BufWriter::new(File::create(path).map_err(|e| e.to_string())?);
+
+        if is_text(path) {
+            for p in compras {
+                writeln!(file, "{} {} {:.2}", p.name, p.qty, p.price).map_err(|e| e.to_string())?;
+            }
+        } else {
+            records::write_records(&mut file, compras).map_err(|e| e.to_string())?;
+        }
+        return file.flush().map_err(|e| e.to_string());
+    }
+}
+
+#[derive(Clone)]
+enum Tree {
+    Ints(PersistentBst<i64>),
+    Compras(PersistentBst<Compra>),
+}
+
+enum Flow {
+    Continue,
+    Quit,
+}
+
+/// Todas as versões da árvore, a atual é a última
+struct Session {
+    history: Vec<Tree>,
+}
+
+impl Session {
+    fn new() -> Session {
+        Session { history: vec![Tree::Ints(PersistentBst::new())] }
+    }
+
+    fn current(&self) -> &Tree {
+        self.history.last().unwrap()
+    }
+
+    /// line_number começa em 1, na primeira linha da entrada
+    fn execute(&mut self, line: &str, line_number: usize) -> Result<Flow, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            return Ok(Flow::Continue);
+        };
+
+        match (command, args) {
+            ("quit" | "exit", []) => return Ok(Flow::Quit),
+            ("help", []) => println!("{}", AJUDA),
+            ("undo", []) => {
+                if self.history.len() == 1 {
+                    return Err("nada para desfazer".to_string());
+                }
+                self.history.pop();
+                println!("desfeito");
+            }
+            ("load", [path]) => {
+                let tree = load(Path::new(path))?;
+                self.history.push(tree);
+                println!("carregado {}", path);
+            }
+            ("mode", ["ints"]) => self.history.push(Tree::Ints(PersistentBst::new())),
+            ("mode", ["compras"]) => self.history.push(Tree::Compras(PersistentBst::new())),
+            ("clear", []) => {
+                let empty = match self.current() {
+                    Tree::Ints(_) => Tree::Ints(PersistentBst::new()),
+                    Tree::Compras(_) => Tree::Compras(PersistentBst::new()),
+                };
+                self.history.push(empty);
+            }
+            _ => {
+                //  comandos sobre a árvore atual; os que a alteram criam uma versão
+                let next = match self.current() {
+                    Tree::Ints(tree) => tree_command(tree, command, args, line_number)?.map(Tree::Ints),
+                    Tree::Compras(tree) => tree_command(tree, command, args, line_number)?.map(Tree::Compras),
+                };
+                self.history.extend(next);
+            }
+        }
+
+        return Ok(Flow::Continue);
+    }
+}
+
+//  Retorna a nova versão da árvore, se o comando a alterou
+fn tree_command<T: Value>(tree: &PersistentBst<T>, command: &str, args: &[&str], line_number: usize) -> Result<Option<PersistentBst<T>>, String> {
+    match (command, args) {
+        ("insert", [_, ..]) => {
+            let next = T::parse(args, line_number)?.into_iter().fold(tree.clone(), |t, value| t.insert(value));
+
+            let inserted = next.total_len() - tree.total_len();
+            println!("{} inserido(s)", inserted);
+            return Ok(if inserted > 0 { Some(next) } else { None });
+        }
+        ("delete", [key]) => {
+            let probe = T::probe(key)?;
+            if !tree.search(&probe) {
+                return Err(format!("{} não encontrado", key));
+            }
+            println!("removido {}", key);
+            return Ok(Some(tree.delete(&probe)));
+        }
+        ("find", [key]) => match tree.get(&T::probe(key)?) {
+            Some(value) => println!("{}", value),
+            None => println!("{} não encontrado", key),
+        },
+        ("min", []) => print_or_empty(tree.minimum()),
+        ("max", []) => print_or_empty(tree.maximum()),
+        ("range", [from, to]) => {
+            let (from, to) = (T::probe(from)?, T::probe(to)?);
+            for value in tree.walk_range(&from, &to) {
+                println!("{}", value);
+            }
+        }
+        ("show", []) => tree.show_tree(),
+        ("stats", []) => {
+            println!("É uma bst? {}", tree.is_bst());
+            println!("Está balanceada? {}", tree.is_balanced());
+            println!("Altura: {}", tree.altura());
+            println!("Número de elementos: {}", tree.total_len());
+            println!("Número de folhas: {}", tree.leafs_len());
+        }
+        ("save", [path]) => {
+            T::save(tree, Path::new(path))?;
+            println!("salvo {}", path);
+        }
+        _ => return Err(format!("comando inválido: {} (veja help)", command)),
+    }
+
+    return Ok(None);
+}
+
+fn print_or_empty<T: Display>(value: Option<&T>) {
+    match value {
+        Some(value) => println!("{}", value),
+        None => println!("(Árvore Vazia)"),
+    }
+}
+
+fn is_text(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "txt")
+}
+
+fn load(path: &Path) -> Result<Tree, String> {
+    let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    if bytes.starts_with(&MAGIC) {
+        let bst: BST<i64> = BST::from_bytes(&bytes).map_err(|e| e.to_string())?;
+        return Ok(Tree::Ints(PersistentBst::from(&bst)));
+    }
+
+    let purchases = if is_text(path) {
+        text_records::read_purchases(bytes.as_slice()).map_err(|e| e.to_string())?
+    } else {
+        records::read_records(bytes.as_slice()).map_err(|e| e.to_string())?
+    };
+
+    //  nomes repetidos são somados; em ordem de nome, from_sorted monta a árvore balanceada
+    let items = text_records::aggregate(purchases).map_err(|e| e.to_string())?;
+    let compras: Vec<Compra> = items.values().cloned().map(Compra).collect();
+    return Ok(Tree::Compras(PersistentBst::from(&BST::from_sorted(compras))));
+}
+
+fn main() {
+    let mut session = Session::new();
+
+    //  a sessão guarda uma árvore só, então apenas um arquivo pode ser carregado
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() > 1 {
+        eprintln!("uso: bst-cli [arquivo]");
+        std::process::exit(2);
+    }
+
+    //  o caminho vai direto para load(), sem passar pelo split dos comandos
+    if let Some(path) = args.first() {
+        match load(Path::new(path)) {
+            Ok(tree) => {
+                session.history.push(tree);
+                println!("carregado {}", path);
+            }
+            Err(msg) => println!("erro: {}", msg),
+        }
+    }
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut line_number = 0;
+
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            Some(Err(e)) => {
+                eprintln!("erro: {}", e);
+                std::process::exit(1);
+            }
+            None => break,
+        };
+        line_number += 1;
+
+        match session.execute(&line, line_number) {
+            Ok(Flow::Continue) => {}
+            Ok(Flow::Quit) => break,
+            Err(msg) => println!("erro: {}", msg),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+/*
+ *  Formato binário compacto da BST, que preserva o formato exato
+ *  da árvore (diferente do serde, que salva apenas os valores).
+ *  Uma árvore carregada é idêntica à salva: mesma altura, mesmo
+ *  show_tree, e to_bytes() gera exatamente os mesmos bytes.
+ *
+ *  Layout (inteiros em little-endian):
+ *      magic       4 bytes     "BST\0"
+ *      versão      1 byte
+ *      nodes       u64         quantidade de nodes
+ *      payload     u64         tamanho do payload em bytes
+ *      payload                 árvore em pré-ordem, cada posição é
+ *                              0 (sem node) ou 1 seguido do valor
+ *      checksum    u32         FNV-1a de tudo que vem antes
+ *
+ *  Usa apenas a interface pública, então vale para os dois backends.
+*/
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, Read, Write},
+};
+
+use crate::{Cursor, BST};
+
+pub const MAGIC: [u8; 4] = *b"BST\0";
+pub const VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 4 + 1 + 8 + 8;
+const CHECKSUM_LEN: usize = 4;
+
+const SEM_NODE: u8 = 0;
+const COM_NODE: u8 = 1;
+
+/// Erros ao carregar uma BST do formato binário
+#[derive(Debug)]
+pub enum FormatError {
+    /// A entrada acabou antes do esperado
+    Truncated,
+    /// A entrada não começa com MAGIC
+    BadMagic,
+    /// Versão do formato não suportada
+    UnsupportedVersion(u8),
+    /// O checksum salvo não bate com o calculado
+    ChecksumMismatch { expected: u32, found: u32 },
+    /// Sobraram bytes depois do checksum ou do payload
+    TrailingBytes,
+    /// Byte de marcação de node diferente de 0 ou 1
+    InvalidMarker(u8),
+    /// Um valor não pôde ser decodificado
+    InvalidValue(String),
+    /// A estrutura salva não é uma BST válida
+    /// (valores fora de ordem, repetidos, ou contagem errada de nodes)
+    NotABst,
+    /// Um valor é grande demais para ser salvo (tamanho em bytes)
+    TooLarge(usize),
+    /// Erro de leitura ou escrita
+    Io(io::Error),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Truncated => write!(f, "entrada truncada"),
+            FormatError::BadMagic => write!(f, "a entrada não é uma BST no formato binário"),
+            FormatError::UnsupportedVersion(v) => write!(f, "versão {} do formato não suportada", v),
+            FormatError::ChecksumMismatch { expected, found } => {
+                write!(f, "checksum inválido: esperado {:#010x}, encontrado {:#010x}", expected, found)
+            }
+            FormatError::TrailingBytes => write!(f, "bytes sobrando no fim da entrada"),
+            FormatError::InvalidMarker(m) => write!(f, "marcador de node inválido: {}", m),
+            FormatError::InvalidValue(msg) => write!(f, "valor inválido: {}", msg),
+            FormatError::NotABst => write!(f, "a estrutura salva não é uma BST válida"),
+            FormatError::TooLarge(len) => write!(f, "valor de {} bytes grande demais para o formato", len),
+            FormatError::Io(e) => write!(f, "erro de E/S: {}", e),
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for FormatError {
+    fn from(e: io::Error) -> Self {
+        FormatError::Io(e)
+    }
+}
+
+/// Valores que sabem se escrever e se ler no formato binário
+pub trait BinaryValue: Sized {
+    /// Escreve o valor no fim de out
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), FormatError>;
+
+    /// Lê um valor do começo de input, avançando input
+    fn decode(input: &mut &[u8]) -> Result<Self, FormatError>;
+}
+
+/// Retira os n primeiros bytes de input <br>
+/// Útil para implementar BinaryValue
+pub fn read_bytes<'a>(input: &mut &'a [u8], n: usize) -> Result<&'a [u8], FormatError> {
+    if input.len() < n {
+        return Err(FormatError::Truncated);
+    }
+    let (bytes, rest) = input.split_at(n);
+    *input = rest;
+    return Ok(bytes);
+}
+
+//  Inteiros e floats são salvos em little-endian com tamanho fixo
+macro_rules! impl_binary_value {
+    ($($t:ty),*) => {$(
+        impl BinaryValue for $t {
+            fn encode(&self, out: &mut Vec<u8>) -> Result<(), FormatError> {
+                out.extend_from_slice(&self.to_le_bytes());
+                Ok(())
+            }
+
+            fn decode(input: &mut &[u8]) -> Result<Self, FormatError> {
+                let bytes = read_bytes(input, size_of::<$t>())?;
+                Ok(<$t>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+        }
+    )*};
+}
+
+impl_binary_value!(u8, u16, u32, u64, i8, i16, i32, i64, f32, f64);
+
+//  Strings são salvas como tamanho (u32) seguido dos bytes em UTF-8
+impl BinaryValue for String {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), FormatError> {
+        let len = u32::try_from(self.len()).map_err(|_| FormatError::TooLarge(self.len()))?;
+        len.encode(out)?;
+        out.extend_from_slice(self.as_bytes());
+        return Ok(());
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, FormatError> {
+        let len = u32::decode(input)? as usize;
+        let bytes = read_bytes(input, len)?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|_| FormatError::InvalidValue("UTF-8 inválido".to_string()))
+    }
+}
+
+//  Pares são salvos como os dois valores em sequência
+impl<A: BinaryValue, B: BinaryValue> BinaryValue for (A, B) {
+    fn encode(&self, out: &mut Vec<u8>) -> Result<(), FormatError> {
+        self.0.encode(out)?;
+        self.1.encode(out)
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, FormatError> {
+        Ok((A::decode(input)?, B::decode(input)?))
+    }
+}
+
+/// FNV-1a de 32 bits, usado como checksum do formato
+pub fn checksum(bytes: &[u8]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for &byte in bytes {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    return hash;
+}
+
+impl<T: PartialOrd + BinaryValue> BST<T> {
+    /// Serializa a árvore, preservando seu formato <br>
+    /// **Err** se algum valor não couber no formato (ex: String com mais de u32::MAX bytes)
+    pub fn to_bytes(&self) -> Result<Vec<u8>, FormatError> {
+        let (nodes, payload) = self.encode_payload()?;
+
+        let mut out = Vec::with_capacity(HEADER_LEN + payload.len() + CHECKSUM_LEN);
+        out.extend_from_slice(&MAGIC);
+        out.push(VERSION);
+        (nodes as u64).encode(&mut out)?;
+        (payload.len() as u64).encode(&mut out)?;
+        out.extend_from_slice(&payload);
+
+        let sum = checksum(&out);
+        sum.encode(&mut out)?;
+        return Ok(out);
+    }
+
+    /// Reconstrói uma árvore salva com to_bytes()
+    pub fn from_bytes(bytes: &[u8]) -> Result<BST<T>, FormatError> {
+        let mut input = bytes;
+
+        if read_bytes(&mut input, MAGIC.len())? != MAGIC {
+            return Err(FormatError::BadMagic);
+        }
+        let version = u8::decode(&mut input)?;
+        if version != VERSION {
+            return Err(FormatError::UnsupportedVersion(version));
+        }
+        let nodes = u64::decode(&mut input)?;
+        let payload_len = usize::try_from(u64::decode(&mut input)?).map_err(|_| FormatError::Truncated)?;
+
+        let payload = read_bytes(&mut input, payload_len)?;
+        let found = u32::decode(&mut input)?;
+        if !input.is_empty() {
+            return Err(FormatError::TrailingBytes);
+        }
+
+        let expected = checksum(&bytes[..bytes.len() - CHECKSUM_LEN]);
+        if expected != found {
+            return Err(FormatError::ChecksumMismatch { expected, found });
+        }
+
+        let bst = BST::decode_payload(payload)?;
+
+        //  Inserir em pré-ordem reproduz o formato salvo apenas se ele
+        //  era uma BST válida; basta conferir que o resultado gera o
+        //  mesmo payload
+        let (encoded_nodes, encoded) = bst.encode_payload()?;
+        if encoded_nodes as u64 != nodes || encoded != payload {
+            return Err(FormatError::NotABst);
+        }
+
+        return Ok(bst);
+    }
+
+    /// Escreve a árvore em w, no mesmo formato de to_bytes()
+    pub fn write_to<W: Write>(&self, mut w: W) -> Result<(), FormatError> {
+        w.write_all(&self.to_bytes()?)?;
+        return Ok(());
+    }
+
+    /// Lê uma árvore escrita com write_to()
+    pub fn read_from<R: Read>(mut r: R) -> Result<BST<T>, FormatError> {
+        let mut bytes = Vec::new();
+        r.read_to_end(&mut bytes)?;
+        BST::from_bytes(&bytes)
+    }
+
+    //  Retorna a quantidade de nodes e o payload em pré-ordem
+    fn encode_payload(&self) -> Result<(usize, Vec<u8>), FormatError> {
+        let mut payload = Vec::new();
+        let mut nodes = 0;
+
+        let root = if self.is_empty() { None } else { Some(self.cursor_root()) };
+        let mut pending: Vec<Option<Cursor<'_, T>>> = vec![root];
+
+        while let Some(cursor) = pending.pop() {
+            let Some(cursor) = cursor else {
+                payload.push(SEM_NODE);
+                continue;
+            };
+
+            payload.push(COM_NODE);
+            cursor.value().unwrap().encode(&mut payload)?;
+            nodes += 1;
+
+            //  a esquerda é desempilhada primeiro
+            pending.push(cursor.right());
+            pending.push(cursor.left());
+        }
+
+        return Ok((nodes, payload));
+    }
+
+    fn decode_payload(mut payload: &[u8]) -> Result<BST<T>, FormatError> {
+        let mut bst = BST::new();
+
+        //  posições (filhos) ainda não lidas: começa pela root
+        let mut open = 1usize;
+
+        while open > 0 {
+            open -= 1;
+
+            match u8::decode(&mut payload)? {
+                SEM_NODE => {}
+                COM_NODE => {
+                    if !bst.insert(T::decode(&mut payload)?) {
+                        return Err(FormatError::NotABst);
+                    }
+                    open += 2;
+                }
+                marker => return Err(FormatError::InvalidMarker(marker)),
+            }
+        }
+
+        if !payload.is_empty() {
+            return Err(FormatError::TrailingBytes);
+        }
+        return Ok(bst);
+    }
+}
@@ -11,7 +11,9 @@
  *  algumas otimizações do compilador.
 */
 
-use std::{cmp, fmt::Display, ptr::NonNull};
+use std::{cmp::{self, Ordering}, fmt::Display, ptr::NonNull};
+
+use crate::{observer::{self, BstObserver, Direction}, show};
 
 /* 
  *  PartialOrd é o trait (interface) atribuido a types que suportam 
@@ -46,10 +48,26 @@ impl <T: PartialOrd> Node<T> {
 }
 
 pub struct BST<T: PartialOrd> {
-    root: Option<NonNull<Node<T>>>
+    pub(crate) root: Option<NonNull<Node<T>>>
 }
 
+//  Os NonNull impedem que o compilador derive Send e Sync.
+//  SAFETY: cada node é alocado por um Box e pertence exclusivamente
+//  à BST que o contém: nenhum outro valor guarda esses ponteiros e
+//  eles só são liberados no drop da árvore. Acessos aos nodes passam
+//  por &self ou &mut self, então as regras de empréstimo da BST valem
+//  para os nodes, como em um Box<Node<T>>: a árvore pode ser enviada
+//  se T puder, e compartilhada se T puder.
+unsafe impl<T: PartialOrd + Send> Send for BST<T> {}
+unsafe impl<T: PartialOrd + Sync> Sync for BST<T> {}
+
 //  Implementação da interface pública
+impl<T: PartialOrd> Default for BST<T> {
+    fn default() -> Self {
+        BST::new()
+    }
+}
+
 impl <T: PartialOrd> BST<T> {
 
     /// Retorna uma nova instância de BST
@@ -72,6 +90,18 @@ impl <T: PartialOrd> BST<T> {
     ///  **false** se o elemento já existia
     ///  **true** se o elemento foi adicionado <br>
     pub fn insert(&mut self, value: T) -> bool {
+        self.insert_traced(value, &mut ())
+    }
+
+    /// Remove um elemento da BST <Br>
+    /// **false** elemento não encontrado <br>
+    /// **true** elemento encontrado e deletado
+    pub fn delete(&mut self, value: &T) -> bool {
+        self.delete_traced(value, &mut ())
+    }
+
+    /// insert() avisando observer de cada passo
+    pub fn insert_traced(&mut self, value: T, observer: &mut impl BstObserver<T>) -> bool {
 
         let node = Node::new(value);
         let leaked = Box::leak(Box::new(node));
@@ -79,27 +109,35 @@ impl <T: PartialOrd> BST<T> {
 
         //  Inserir na root vazia
         if self.root.is_none() {
+            unsafe {
+                observer.link(&node_ptr.as_ref().value, None);
+                node_ptr.as_mut().parent = None;
+            }
             self.root = Some(node_ptr);
-            unsafe { node_ptr.as_mut().parent = None };
             return true;
         }
 
-        return BST::insert_recursive(self.root.unwrap(), node_ptr);
+        let inserted = BST::insert_recursive(self.root.unwrap(), node_ptr, observer);
+
+        //  o valor já existia, o node criado não foi ligado à árvore
+        //  e precisa ser desalocado aqui
+        if !inserted {
+            drop(unsafe { Box::from_raw(node_ptr.as_ptr()) });
+        }
+        return inserted;
     }
 
-    /// Remove um elemento da BST <Br>
-    /// **false** elemento não encontrado <br>
-    /// **true** elemento encontrado e deletado
-    pub fn delete(&mut self, value: &T) -> bool {
+    /// delete() avisando observer de cada passo
+    pub fn delete_traced(&mut self, value: &T, observer: &mut impl BstObserver<T>) -> bool {
 
-        let Some(node) = BST::get_node(self.root, value) else {
+        let Some(node) = self.find_node_observed(value, observer) else {
             return false; //    não há node para deletar
         };
-        self.delete_node(node);
-        
+        self.delete_node_observed(node, observer);
+
         return true;
     }
-    
+
     pub fn get(&self, value: &T) -> Option<&T> {
         let node = BST::get_node(self.root, value);
         match node {
@@ -174,32 +212,49 @@ impl <T: PartialOrd> BST<T> {
 
 }
 
+//  Desaloca todos os nodes quando a BST sai de escopo
+//  Iterativo para não estourar a pilha em árvores degeneradas
+impl<T: PartialOrd> Drop for BST<T> {
+    fn drop(&mut self) {
+        let mut pending: Vec<NonNull<Node<T>>> = self.root.take().into_iter().collect();
+
+        while let Some(node) = pending.pop() {
+            let node = unsafe { Box::from_raw(node.as_ptr()) };
+            pending.extend(node.left);
+            pending.extend(node.right);
+        }
+    }
+}
+
 //  Interface privada
 impl<T: PartialOrd> BST<T> {
 
     /// Função helper para BST::insert()
-    fn insert_recursive(mut node: NonNull<Node<T>>, mut target: NonNull<Node<T>>) -> bool {
+    fn insert_recursive(mut node: NonNull<Node<T>>, mut target: NonNull<Node<T>>, observer: &mut impl BstObserver<T>) -> bool {
         
         unsafe {
             let target_value = &target.as_ref().value;
             let node_value = &node.as_ref().value;
 
-            if target_value == node_value { return false; }
-            
-            if target_value < node_value {
-                if let Some(left) = node.as_ref().left {
-                    return BST::insert_recursive(left, target);
-                }
-                target.as_mut().parent = Some(node);
-                node.as_mut().left = Some(target);
+            let ordering = observer::ordering(target_value, node_value);
+            observer.compare(target_value, node_value, ordering);
+
+            let (next, direction) = match ordering {
+                Ordering::Equal => return false,
+                Ordering::Less => (node.as_ref().left, Direction::Left),
+                Ordering::Greater => (node.as_ref().right, Direction::Right),
+            };
+
+            if let Some(next) = next {
+                observer.descend(node_value, direction);
+                return BST::insert_recursive(next, target, observer);
             }
 
-            else if target_value > node_value {
-                if let Some(right) = node.as_ref().right {
-                    return BST::insert_recursive(right, target);
-                }
-                target.as_mut().parent = Some(node);
-                node.as_mut().right = Some(target);
+            observer.link(target_value, Some((node_value, direction)));
+            target.as_mut().parent = Some(node);
+            match direction {
+                Direction::Left => node.as_mut().left = Some(target),
+                Direction::Right => node.as_mut().right = Some(target),
             }
 
             return true;
@@ -208,10 +263,10 @@ impl<T: PartialOrd> BST<T> {
     }
 
     //  Retorna um ponteiro (não único) para um Node
-    fn get_node(node: Option<NonNull<Node<T>>>, value: &T) -> Option<NonNull<Node<T>>> {
+    pub(crate) fn get_node(node: Option<NonNull<Node<T>>>, value: &T) -> Option<NonNull<Node<T>>> {
 
         //  Chegou ao final da BST e o valor não foi encontrado
-        let Some(mut root) = node else {
+        let Some(root) = node else {
             return node;
         };
 
@@ -220,11 +275,11 @@ impl<T: PartialOrd> BST<T> {
         unsafe {
             //  valor menor do que o root, comparar a esquerda
             if  value < &root.as_ref().value {
-                return BST::get_node(root.as_mut().left, value);
+                return BST::get_node(root.as_ref().left, value);
             }
             //  valor maior do que o root, comparar a direita
             else if value > &root.as_ref().value {
-                return BST::get_node(root.as_mut().right, value);
+                return BST::get_node(root.as_ref().right, value);
             }
             //  valor e  ncontrado, retornar o node (root)
             else {
@@ -236,7 +291,7 @@ impl<T: PartialOrd> BST<T> {
 
     //  Retorna um ponteiro (não único) para o menor node da sub-árvore
     //  esse node pode ser a própria root, se não houver filhos
-    fn minimum_helper(mut node: NonNull<Node<T>>) -> NonNull<Node<T>> {
+    pub(crate) fn minimum_helper(mut node: NonNull<Node<T>>) -> NonNull<Node<T>> {
         unsafe {
     
             while let Some(left) = node.as_ref().left {
@@ -249,7 +304,7 @@ impl<T: PartialOrd> BST<T> {
 
     //  Retorna um ponteiro (não único) para o maior node da sub-árvore
     //  esse node pode ser a própria root, se não houver filhos
-    fn maximum_helper(mut node: NonNull<Node<T>>) -> NonNull<Node<T>> {
+    pub(crate) fn maximum_helper(mut node: NonNull<Node<T>>) -> NonNull<Node<T>> {
         unsafe {
     
             while let Some(right) = node.as_ref().right {
@@ -263,7 +318,7 @@ impl<T: PartialOrd> BST<T> {
     //  Retorna um ponteiro (não exclusivo) para o sucessor de um Node
     //  na bst.
     //  Pode ser que não haja um sucessor (None)
-    fn sucessor(node: NonNull<Node<T>>) -> Option<NonNull<Node<T>>> {   
+    pub(crate) fn sucessor(node: NonNull<Node<T>>) -> Option<NonNull<Node<T>>> {   
         let sucessor: Option<NonNull<Node<T>>>;
 
         unsafe {
@@ -272,12 +327,14 @@ impl<T: PartialOrd> BST<T> {
                 sucessor = Some(BST::minimum_helper(right));
             }
             else {
+                //  sobe enquanto o node atual for o filho direito do seu parent
+                let mut current = node;
                 let mut parent= node.as_ref().parent; 
 
                 while let Some(p) = parent {
-                    if Some(node) == p.as_ref().right {
-                        parent = p.as_ref().parent;
-                    }
+                    if Some(current) != p.as_ref().right {break;}
+                    current = p;
+                    parent = p.as_ref().parent;
                 }
                 sucessor = parent;
             }
@@ -287,6 +344,29 @@ impl<T: PartialOrd> BST<T> {
 
     }
 
+    //  Retorna um ponteiro (não exclusivo) para o antecessor de um Node
+    //  na bst, simétrico ao sucessor.
+    //  Pode ser que não haja um antecessor (None)
+    pub(crate) fn antecessor(node: NonNull<Node<T>>) -> Option<NonNull<Node<T>>> {
+        unsafe {
+
+            if let Some(left) = node.as_ref().left {
+                return Some(BST::maximum_helper(left));
+            }
+
+            //  sobe enquanto o node atual for o filho esquerdo do seu parent
+            let mut current = node;
+            let mut parent = node.as_ref().parent;
+
+            while let Some(p) = parent {
+                if Some(current) != p.as_ref().left {break;}
+                current = p;
+                parent = p.as_ref().parent;
+            }
+            return parent;
+        }
+    }
+
     //  Coloca n2 (e sua subarvore) no lugar de n1 (e sua subarvore) na árvore
     //  n1 é removido (mas não deletado) da árvore
     fn transplant(&mut self, n1: NonNull<Node<T>>, n2: Option<NonNull<Node<T>>>) { 
@@ -320,7 +400,35 @@ impl<T: PartialOrd> BST<T> {
         }
     }
 
-    fn delete_node(&mut self, mut node: NonNull<Node<T>>) {
+    //  get_node() avisando observer de cada passo
+    fn find_node_observed(&self, value: &T, observer: &mut impl BstObserver<T>) -> Option<NonNull<Node<T>>> {
+        let mut current = self.root;
+
+        while let Some(node) = current {
+            let node_ref = unsafe { node.as_ref() };
+            let ordering = observer::ordering(value, &node_ref.value);
+            observer.compare(value, &node_ref.value, ordering);
+
+            let (next, direction) = match ordering {
+                Ordering::Less => (node_ref.left, Direction::Left),
+                Ordering::Greater => (node_ref.right, Direction::Right),
+                Ordering::Equal => return Some(node),
+            };
+            if next.is_some() {
+                observer.descend(&node_ref.value, direction);
+            }
+            current = next;
+        }
+
+        return None;
+    }
+
+    //  Remove o node da árvore e devolve o valor que ele guardava
+    pub(crate) fn delete_node(&mut self, node: NonNull<Node<T>>) -> T {
+        self.delete_node_observed(node, &mut ())
+    }
+
+    fn delete_node_observed(&mut self, mut node: NonNull<Node<T>>, observer: &mut impl BstObserver<T>) -> T {
         unsafe {
 
             let left = node.as_mut().left.take();
@@ -328,23 +436,24 @@ impl<T: PartialOrd> BST<T> {
     
             match (left, right) {
                 (None, None) => {
-                    self.transplant(node, None);
+                    self.transplant_observed(node, None, observer);
                 },
                 (None, Some(right)) => {
-                    self.transplant(node, Some(right));
+                    self.transplant_observed(node, Some(right), observer);
                 },
                 (Some(left), None) => {
-                    self.transplant(node, Some(left));
+                    self.transplant_observed(node, Some(left), observer);
                 },
                 (Some(mut left), Some(mut right)) => {
                     //  Há um filho a direita, ou seja, é impossível o unwarp falhar.
                     let mut sucessor = BST::minimum_helper(right);
+                    observer.sucessor(&node.as_ref().value, &sucessor.as_ref().value);
 
                     //  Se o sucessor não está diretamente a direita do node, são necessários 2 transplantes
                     if sucessor.as_ref().parent != Some(node) {
                         //  sucessor é substituido por seu nó a direita na árvore
                         //  sucessor agora está fora da árvore
-                        self.transplant(sucessor, sucessor.as_ref().right);
+                        self.transplant_observed(sucessor, sucessor.as_ref().right, observer);
 
                         //  sucessor.right é ligado (e vice-versa) ao filho direiro de node
                         right.as_mut().parent = Some(sucessor);
@@ -357,15 +466,23 @@ impl<T: PartialOrd> BST<T> {
 
                     //  node é trocado por sucessor
                     //  node agora está fora da árvore
-                    self.transplant(node, Some(sucessor));
+                    self.transplant_observed(node, Some(sucessor), observer);
                 }
             }
     
-            //  desaloca o node deletado
-            drop(Box::from_raw(node.as_ptr()));
+            //  desaloca o node deletado, mantendo apenas o valor
+            let node = Box::from_raw(node.as_ptr());
+            return node.value;
         }
     }
 
+    fn transplant_observed(&mut self, n1: NonNull<Node<T>>, n2: Option<NonNull<Node<T>>>, observer: &mut impl BstObserver<T>) {
+        unsafe {
+            observer.transplant(&n1.as_ref().value, n2.map(|n| &(*n.as_ptr()).value));
+        }
+        self.transplant(n1, n2);
+    }
+
     fn walk_recursive(node: Option<NonNull<Node<T>>>, vec: &mut Vec<&T>)  {
         unsafe {
             if let Some(node) = node {
@@ -412,8 +529,8 @@ impl<T: PartialOrd> BST<T> {
             let left_ok = BST::is_balanced_recursive(node.as_ref().left);
             let right_ok = BST::is_balanced_recursive(node.as_ref().right);
             let height_balanced = 
-                BST::altura_recursiva(node.as_ref().left) as i32 - 
-                BST::altura_recursiva(node.as_ref().right) as i32 <= 1;
+                (BST::altura_recursiva(node.as_ref().left) as i32 - 
+                BST::altura_recursiva(node.as_ref().right) as i32).abs() <= 1;
 
             return left_ok && right_ok && height_balanced;
         }
@@ -457,53 +574,70 @@ impl<T: PartialOrd> BST<T> {
 
 }
 
-//  Print da árvore para Ts que implementam Display
-//  (Ts que podem ser printados)
-//  Feito com IA para se parecer com um gerenciador de arquivos
-impl<T: Display + PartialOrd> BST<T> {
-    /// Método público para iniciar a impressão visual da árvore.
-    pub fn show_tree(&self) {
-        if let Some(root_ptr) = self.root {
-            unsafe {
-                BST::print_recursive(root_ptr, 0, true);
+//  Buscas guiadas por uma função de comparação
+//  f(valor) compara o alvo com o valor do node, como em alvo.cmp(valor),
+//  permitindo buscar por apenas uma parte do valor (uma chave, por exemplo)
+impl<T: PartialOrd> BST<T> {
+    fn get_node_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Option<NonNull<Node<T>>> {
+        let mut current = self.root;
+
+        while let Some(node) = current {
+            let node_ref = unsafe { node.as_ref() };
+
+            match f(&node_ref.value) {
+                Ordering::Less => current = node_ref.left,
+                Ordering::Greater => current = node_ref.right,
+                Ordering::Equal => return Some(node),
             }
-        } else {
-            println!("(Árvore Vazia)");
         }
+
+        return None;
     }
 
-    // Função auxiliar recursiva que faz o trabalho pesado
-    // Usa 'prefix' para desenhar a linha vertical da indentação
-    unsafe fn print_recursive(node_ptr: NonNull<Node<T>>, depth: usize, eh_o_ultimo: bool) {
-        let node = node_ptr.as_ref();
+    //  Primeiro node cujo valor não é menor que o alvo
+    pub(crate) fn lower_bound_node_by(&self, mut f: impl FnMut(&T) -> Ordering) -> Option<NonNull<Node<T>>> {
+        let mut current = self.root;
+        let mut candidate = None;
 
-        // 1. Imprimir o nó atual
-        // Cria a string de indentação e o prefixo (branch)
-        let indent = "│   ".repeat(depth);
-        let branch = if eh_o_ultimo { "└── " } else { "├── " };
-        
-        println!("{}{}{}", indent, branch, node.value);
+        while let Some(node) = current {
+            let node_ref = unsafe { node.as_ref() };
 
-        // O novo prefixo para os filhos é a indentação + o espaço vertical
-        let new_indent = indent + if eh_o_ultimo { "    " } else { "│   " };
-        
-        // Determinar se o filho esquerdo será o "último" (se o direito for None)
-        let left_is_last = node.right.is_none();
-
-        // 2. Chamada Recursiva para o filho ESQUERDO
-        if let Some(left_ptr) = node.left {
-            // Passa a nova profundidade e se este filho será o último do seu nível
-            Self::print_recursive(left_ptr, depth + 1, left_is_last);
-        } else if node.right.is_some() {
-            // Desenha um nó 'vazio' se o filho direito existir, para manter o alinhamento
-            println!("{}{}└── (Vazio)", new_indent, if left_is_last { "    " } else { "│   " });
+            if f(&node_ref.value) == Ordering::Greater {
+                current = node_ref.right;
+            } else {
+                candidate = Some(node);
+                current = node_ref.left;
+            }
         }
 
+        return candidate;
+    }
 
-        // 3. Chamada Recursiva para o filho DIREITO
-        if let Some(right_ptr) = node.right {
-            // O filho direito SEMPRE é o último elemento a ser desenhado naquele nível
-            Self::print_recursive(right_ptr, depth + 1, true);
-        }
+    pub(crate) fn get_by(&self, f: impl FnMut(&T) -> Ordering) -> Option<&T> {
+        self.get_node_by(f).map(|n| unsafe { &(*n.as_ptr()).value })
+    }
+
+    //  Cuidado: alterar a parte do valor usada na comparação quebra a BST
+    pub(crate) fn get_mut_by(&mut self, f: impl FnMut(&T) -> Ordering) -> Option<&mut T> {
+        self.get_node_by(f).map(|n| unsafe { &mut (*n.as_ptr()).value })
+    }
+
+    pub(crate) fn remove_by(&mut self, f: impl FnMut(&T) -> Ordering) -> Option<T> {
+        let node = self.get_node_by(f)?;
+        return Some(self.delete_node(node));
+    }
+}
+
+//  Print da árvore para Ts que implementam Display
+//  (Ts que podem ser printados)
+//  Feito com IA para se parecer com um gerenciador de arquivos
+impl<T: Display + PartialOrd> BST<T> {
+    /// Método público para iniciar a impressão visual da árvore.
+    pub fn show_tree(&self) {
+        //  os nodes vivem enquanto a árvore estiver emprestada
+        show::show_tree(self.root, |ptr| {
+            let node = unsafe { ptr.as_ref() };
+            (&node.value, node.left, node.right)
+        });
     }
 }
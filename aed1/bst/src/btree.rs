@@ -0,0 +1,742 @@
+/*
+ *  Árvore B guardada em páginas de tamanho fixo (um node por página),
+ *  para registros em memória externa: cada node tem várias chaves,
+ *  então a altura (e o número de páginas lidas por busca) é bem menor
+ *  que a de uma BST.
+ *
+ *  A ordem m é o número máximo de filhos de um node:
+ *      - cada node tem no máximo m - 1 chaves
+ *      - todo node, exceto a root, tem no mínimo ceil(m / 2) - 1 chaves
+ *      - todas as folhas estão na mesma profundidade
+ *  Chaves e valores ficam em todos os nodes (não é uma árvore B+).
+ *
+ *  Página 0 (cabeçalho), inteiros em little-endian:
+ *      magic       4 bytes     "BTR\0"
+ *      versão      1 byte
+ *      page_size   u32
+ *      ordem       u32
+ *      root        u64         0 se a árvore está vazia
+ *      len         u64         quantidade de chaves
+ *      livre       u64         primeira página da lista de páginas livres, 0 se não há
+ *
+ *  Demais páginas:
+ *      node        tipo (1 folha, 2 interno), u16 com a quantidade de
+ *                  chaves, os pares (chave, valor) e, nos internos,
+ *                  os filhos (u64)
+ *      livre       tipo 0 e u64 com a próxima página livre
+ *  Chaves e valores usam o BinaryValue de binary_format.rs.
+*/
+
+use std::{
+    error::Error,
+    fmt,
+    io,
+    marker::PhantomData,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::{
+    binary_format::{read_bytes, BinaryValue, FormatError},
+    pager::{PageId, Pager},
+};
+
+pub const MAGIC: [u8; 4] = *b"BTR\0";
+pub const VERSION: u8 = 1;
+
+const HEADER_LEN: usize = 4 + 1 + 4 + 4 + 8 + 8 + 8;
+const HEADER_PAGE: PageId = 0;
+
+const PAGINA_LIVRE: u8 = 0;
+const FOLHA: u8 = 1;
+const INTERNO: u8 = 2;
+
+//  tipo e quantidade de chaves
+const NODE_HEADER_LEN: usize = 1 + 2;
+const CHILD_LEN: usize = 8;
+
+/// Erros da BTree
+#[derive(Debug)]
+pub enum BTreeError {
+    Io(io::Error),
+    /// Uma página não pôde ser decodificada
+    Format(FormatError),
+    /// A ordem precisa ser pelo menos 3 (e caber no u16 da página)
+    InvalidOrder(usize),
+    /// Um node cheio com a ordem pedida não cabe na página
+    PageTooSmall { page_size: usize, order: usize },
+    /// O par (chave, valor) codificado passa do limite por entrada,
+    /// que garante que qualquer node cabe em uma página
+    EntryTooLarge { size: usize, max: usize },
+    /// O pager passado para create() já tem páginas
+    PagerNotEmpty,
+    /// O tamanho de página salvo no cabeçalho é diferente do pager
+    PageSizeMismatch { expected: usize, found: usize },
+}
+
+impl fmt::Display for BTreeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BTreeError::Io(e) => write!(f, "erro de E/S: {}", e),
+            BTreeError::Format(e) => write!(f, "página inválida: {}", e),
+            BTreeError::InvalidOrder(order) => write!(f, "ordem inválida: {}", order),
+            BTreeError::PageTooSmall { page_size, order } => {
+                write!(f, "páginas de {} bytes são pequenas demais para a ordem {}", page_size, order)
+            }
+            BTreeError::EntryTooLarge { size, max } => {
+                write!(f, "entrada com {} bytes, o máximo é {}", size, max)
+            }
+            BTreeError::PagerNotEmpty => write!(f, "o pager já tem páginas"),
+            BTreeError::PageSizeMismatch { expected, found } => {
+                write!(f, "árvore salva com páginas de {} bytes, o pager usa {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for BTreeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BTreeError::Io(e) => Some(e),
+            BTreeError::Format(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for BTreeError {
+    fn from(e: io::Error) -> Self {
+        BTreeError::Io(e)
+    }
+}
+
+impl From<FormatError> for BTreeError {
+    fn from(e: FormatError) -> Self {
+        BTreeError::Format(e)
+    }
+}
+
+//  Node decodificado de uma página. Folhas não têm filhos
+struct Node<K, V> {
+    entries: Vec<(K, V)>,
+    children: Vec<PageId>,
+}
+
+impl<K: Ord, V> Node<K, V> {
+    fn leaf(entries: Vec<(K, V)>) -> Node<K, V> {
+        Node { entries, children: Vec::new() }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    //  Ok(i) se a chave está em entries[i], Err(i) com o filho onde ela estaria
+    fn search(&self, key: &K) -> Result<usize, usize> {
+        self.entries.binary_search_by(|(k, _)| k.cmp(key))
+    }
+}
+
+pub struct BTree<K, V, P: Pager> {
+    pager: P,
+    order: usize,
+    root: Option<PageId>,
+    len: u64,
+    free: Option<PageId>,
+    _marker: PhantomData<(K, V)>,
+}
+
+impl<K: Ord + BinaryValue, V: BinaryValue, P: Pager> BTree<K, V, P> {
+
+    /// Cria uma árvore vazia de ordem order em um pager sem páginas
+    pub fn create(mut pager: P, order: usize) -> Result<BTree<K, V, P>, BTreeError> {
+        if pager.page_count() != 0 {
+            return Err(BTreeError::PagerNotEmpty);
+        }
+        check_order(pager.page_size(), order)?;
+
+        pager.allocate()?;
+        let mut tree = BTree { pager, order, root: None, len: 0, free: None, _marker: PhantomData };
+        tree.write_header()?;
+        return Ok(tree);
+    }
+
+    /// Abre uma árvore criada com create() em um pager
+    pub fn open(pager: P) -> Result<BTree<K, V, P>, BTreeError> {
+        let page_size = pager.page_size();
+        if page_size < HEADER_LEN {
+            return Err(BTreeError::PageTooSmall { page_size, order: 0 });
+        }
+
+        let mut page = vec![0; page_size];
+        pager.read_page(HEADER_PAGE, &mut page)?;
+        let mut input = page.as_slice();
+
+        if read_bytes(&mut input, MAGIC.len())? != MAGIC {
+            return Err(FormatError::BadMagic.into());
+        }
+        let version = u8::decode(&mut input)?;
+        if version != VERSION {
+            return Err(FormatError::UnsupportedVersion(version).into());
+        }
+        let saved_size = u32::decode(&mut input)? as usize;
+        if saved_size != page_size {
+            return Err(BTreeError::PageSizeMismatch { expected: saved_size, found: page_size });
+        }
+        let order = u32::decode(&mut input)? as usize;
+        check_order(page_size, order)?;
+
+        let root = non_zero(u64::decode(&mut input)?);
+        let len = u64::decode(&mut input)?;
+        let free = non_zero(u64::decode(&mut input)?);
+
+        return Ok(BTree { pager, order, root, len, free, _marker: PhantomData });
+    }
+
+    /// Devolve o pager, por exemplo para reabrir a árvore com open()
+    pub fn into_pager(self) -> P {
+        self.pager
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Quantidade de chaves na árvore
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Tamanho máximo de uma chave e valor codificados juntos
+    pub fn max_entry_size(&self) -> usize {
+        max_entry_size(self.pager.page_size(), self.order)
+    }
+
+    /// Quantidade de páginas do pager, incluindo o cabeçalho e as livres
+    pub fn page_count(&self) -> u64 {
+        self.pager.page_count()
+    }
+
+    /// Quantidade de níveis (0 se a árvore está vazia)
+    pub fn height(&self) -> Result<usize, BTreeError> {
+        let mut height = 0;
+        let mut current = self.root;
+
+        while let Some(page) = current {
+            height += 1;
+            current = self.read_node(page)?.children.first().copied();
+        }
+
+        return Ok(height);
+    }
+
+    pub fn get(&self, key: &K) -> Result<Option<V>, BTreeError> {
+        let mut current = self.root;
+
+        while let Some(page) = current {
+            let mut node = self.read_node(page)?;
+            match node.search(key) {
+                Ok(i) => return Ok(Some(node.entries.swap_remove(i).1)),
+                Err(i) => current = node.children.get(i).copied(),
+            }
+        }
+
+        return Ok(None);
+    }
+
+    pub fn contains_key(&self, key: &K) -> Result<bool, BTreeError> {
+        Ok(self.get(key)?.is_some())
+    }
+
+    /// Associa value a key <br>
+    /// Retorna o valor anterior, se key já existia
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, BTreeError> {
+        //  conferido antes de mexer na árvore, para não deixá-la pela metade
+        let size = encoded_len(&key, &value)?;
+        let max = self.max_entry_size();
+        if size > max {
+            return Err(BTreeError::EntryTooLarge { size, max });
+        }
+
+        let Some(root) = self.root else {
+            let page = self.allocate_page()?;
+            self.write_node(page, &Node::leaf(vec![(key, value)]))?;
+            self.root = Some(page);
+            self.len = 1;
+            self.write_header()?;
+            return Ok(None);
+        };
+
+        let (old, split) = self.insert_into(root, key, value)?;
+
+        //  a root foi dividida: a árvore cresce um nível
+        if let Some((separator, right)) = split {
+            let page = self.allocate_page()?;
+            self.write_node(page, &Node { entries: vec![separator], children: vec![root, right] })?;
+            self.root = Some(page);
+        }
+
+        if old.is_none() {
+            self.len += 1;
+            self.write_header()?;
+        }
+        return Ok(old);
+    }
+
+    //  Insere na subárvore de page. Se o node estourar, ele é dividido e
+    //  a chave do meio sobe, junto com a página da metade direita
+    #[allow(clippy::type_complexity)]
+    fn insert_into(&mut self, page: PageId, key: K, value: V) -> Result<(Option<V>, Option<((K, V), PageId)>), BTreeError> {
+        let mut node = self.read_node(page)?;
+
+        let i = match node.search(&key) {
+            Ok(i) => {
+                let old = std::mem::replace(&mut node.entries[i].1, value);
+                self.write_node(page, &node)?;
+                return Ok((Some(old), None));
+            }
+            Err(i) => i,
+        };
+
+        if node.is_leaf() {
+            node.entries.insert(i, (key, value));
+        } else {
+            let (old, split) = self.insert_into(node.children[i], key, value)?;
+            let Some((separator, right)) = split else {
+                return Ok((old, None));
+            };
+            node.entries.insert(i, separator);
+            node.children.insert(i + 1, right);
+        }
+
+        if node.entries.len() <= self.max_keys() {
+            self.write_node(page, &node)?;
+            return Ok((None, None));
+        }
+
+        let mid = node.entries.len() / 2;
+        let right = Node {
+            entries: node.entries.split_off(mid + 1),
+            children: if node.is_leaf() { Vec::new() } else { node.children.split_off(mid + 1) },
+        };
+        let separator = node.entries.pop().unwrap();
+
+        let right_page = self.allocate_page()?;
+        self.write_node(right_page, &right)?;
+        self.write_node(page, &node)?;
+        return Ok((None, Some((separator, right_page))));
+    }
+
+    /// Remove key, retornando seu valor se existia
+    pub fn remove(&mut self, key: &K) -> Result<Option<V>, BTreeError> {
+        let Some(root) = self.root else {return Ok(None);};
+
+        let removed = self.remove_from(root, key)?;
+        if removed.is_none() {
+            return Ok(None);
+        }
+
+        //  a root ficou sem chaves: a árvore perde um nível (ou fica vazia)
+        let node = self.read_node(root)?;
+        if node.entries.is_empty() {
+            self.root = node.children.first().copied();
+            self.free_page(root)?;
+        }
+
+        self.len -= 1;
+        self.write_header()?;
+        return Ok(removed);
+    }
+
+    fn remove_from(&mut self, page: PageId, key: &K) -> Result<Option<V>, BTreeError> {
+        let mut node = self.read_node(page)?;
+
+        let removed = match node.search(key) {
+            Ok(i) if node.is_leaf() => Some(node.entries.remove(i).1),
+            Ok(i) => {
+                //  troca pelo antecessor, que está sempre em uma folha
+                let predecessor = self.remove_max(node.children[i])?;
+                let (_, value) = std::mem::replace(&mut node.entries[i], predecessor);
+                self.fix_child(&mut node, i)?;
+                Some(value)
+            }
+            Err(_) if node.is_leaf() => None,
+            Err(i) => {
+                let removed = self.remove_from(node.children[i], key)?;
+                if removed.is_none() {
+                    return Ok(None);
+                }
+                self.fix_child(&mut node, i)?;
+                removed
+            }
+        };
+
+        if removed.is_some() {
+            self.write_node(page, &node)?;
+        }
+        return Ok(removed);
+    }
+
+    //  Remove e retorna a maior chave da subárvore de page
+    fn remove_max(&mut self, page: PageId) -> Result<(K, V), BTreeError> {
+        let mut node = self.read_node(page)?;
+
+        let max = if node.is_leaf() {
+            node.entries.pop().expect("node sem chaves fora da root")
+        } else {
+            let last = node.children.len() - 1;
+            let max = self.remove_max(node.children[last])?;
+            self.fix_child(&mut node, last)?;
+            max
+        };
+
+        self.write_node(page, &node)?;
+        return Ok(max);
+    }
+
+    //  Depois de uma remoção no filho i de parent, garante que ele tem o
+    //  mínimo de chaves, pegando uma emprestada de um irmão ou juntando-se
+    //  a um deles. parent é alterado mas não é escrito
+    fn fix_child(&mut self, parent: &mut Node<K, V>, i: usize) -> Result<(), BTreeError> {
+        let mut child = self.read_node(parent.children[i])?;
+        if child.entries.len() >= self.min_keys() {
+            return Ok(());
+        }
+
+        let mut left = None;
+        if i > 0 {
+            let mut sibling = self.read_node(parent.children[i - 1])?;
+            if sibling.entries.len() > self.min_keys() {
+                //  a maior chave do irmão sobe e o separador desce
+                let borrowed = sibling.entries.pop().unwrap();
+                let separator = std::mem::replace(&mut parent.entries[i - 1], borrowed);
+                child.entries.insert(0, separator);
+                if !sibling.is_leaf() {
+                    child.children.insert(0, sibling.children.pop().unwrap());
+                }
+
+                self.write_node(parent.children[i - 1], &sibling)?;
+                self.write_node(parent.children[i], &child)?;
+                return Ok(());
+            }
+            left = Some(sibling);
+        }
+
+        if i + 1 < parent.children.len() {
+            let mut sibling = self.read_node(parent.children[i + 1])?;
+            if sibling.entries.len() > self.min_keys() {
+                let borrowed = sibling.entries.remove(0);
+                let separator = std::mem::replace(&mut parent.entries[i], borrowed);
+                child.entries.push(separator);
+                if !sibling.is_leaf() {
+                    child.children.push(sibling.children.remove(0));
+                }
+
+                self.write_node(parent.children[i + 1], &sibling)?;
+                self.write_node(parent.children[i], &child)?;
+                return Ok(());
+            }
+
+            if left.is_none() {
+                return self.merge(parent, i, child, sibling);
+            }
+        }
+
+        //  sem irmão direito (ou com os dois irmãos no mínimo): junta com o esquerdo
+        let left = left.expect("node fora da root sem irmãos");
+        return self.merge(parent, i - 1, left, child);
+    }
+
+    //  Junta os filhos i e i + 1 de parent, com o separador entre eles,
+    //  na página do filho i; a página do filho i + 1 é liberada
+    fn merge(&mut self, parent: &mut Node<K, V>, i: usize, mut left: Node<K, V>, right: Node<K, V>) -> Result<(), BTreeError> {
+        let separator = parent.entries.remove(i);
+        let right_page = parent.children.remove(i + 1);
+
+        left.entries.push(separator);
+        left.entries.extend(right.entries);
+        left.children.extend(right.children);
+
+        self.write_node(parent.children[i], &left)?;
+        self.free_page(right_page)?;
+        return Ok(());
+    }
+
+    /// Pares em ordem crescente de chave <br>
+    /// As páginas são lidas conforme o iterador avança
+    pub fn iter(&self) -> Range<'_, K, V, P, std::ops::RangeFull> {
+        self.range(..)
+    }
+
+    /// Pares com chave dentro de range, em ordem crescente
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Range<'_, K, V, P, R> {
+        Range { tree: self, stack: Vec::new(), range, started: false, done: false }
+    }
+
+    /// Escreve o cabeçalho e sincroniza o pager
+    pub fn flush(&mut self) -> Result<(), BTreeError> {
+        self.write_header()?;
+        self.pager.sync()?;
+        return Ok(());
+    }
+
+    /// Confere as propriedades da árvore B: chaves em ordem, quantidade
+    /// de chaves por node, folhas na mesma profundidade e len
+    pub fn is_valid(&self) -> Result<bool, BTreeError> {
+        let Some(root) = self.root else {return Ok(self.len == 0);};
+
+        let mut leaf_depth = None;
+        let mut count = 0;
+        let valid = self.is_valid_node(root, 0, (None, None), &mut leaf_depth, &mut count)?;
+
+        return Ok(valid && count == self.len);
+    }
+
+    fn is_valid_node(&self, page: PageId, depth: usize, bounds: (Option<&K>, Option<&K>), leaf_depth: &mut Option<usize>, count: &mut u64) -> Result<bool, BTreeError> {
+        let node = self.read_node(page)?;
+        let keys = node.entries.len();
+        *count += keys as u64;
+
+        let min = if depth == 0 { 1 } else { self.min_keys() };
+        if keys < min || keys > self.max_keys() {
+            return Ok(false);
+        }
+
+        let in_order = node.entries.windows(2).all(|w| w[0].0 < w[1].0);
+        let above_low = bounds.0.is_none_or(|low| node.entries[0].0 > *low);
+        let below_high = bounds.1.is_none_or(|high| node.entries[keys - 1].0 < *high);
+        if !in_order || !above_low || !below_high {
+            return Ok(false);
+        }
+
+        if node.is_leaf() {
+            return Ok(*leaf_depth.get_or_insert(depth) == depth);
+        }
+        if node.children.len() != keys + 1 {
+            return Ok(false);
+        }
+
+        for (i, &child) in node.children.iter().enumerate() {
+            let low = if i == 0 { bounds.0 } else { Some(&node.entries[i - 1].0) };
+            let high = if i == keys { bounds.1 } else { Some(&node.entries[i].0) };
+            if !self.is_valid_node(child, depth + 1, (low, high), leaf_depth, count)? {
+                return Ok(false);
+            }
+        }
+
+        return Ok(true);
+    }
+
+    fn max_keys(&self) -> usize {
+        self.order - 1
+    }
+
+    fn min_keys(&self) -> usize {
+        self.order.div_ceil(2) - 1
+    }
+
+    fn write_header(&mut self) -> Result<(), BTreeError> {
+        let mut out = Vec::with_capacity(self.pager.page_size());
+        out.extend_from_slice(&MAGIC);
+        out.push(VERSION);
+        (self.pager.page_size() as u32).encode(&mut out)?;
+        (self.order as u32).encode(&mut out)?;
+        self.root.unwrap_or(0).encode(&mut out)?;
+        self.len.encode(&mut out)?;
+        self.free.unwrap_or(0).encode(&mut out)?;
+
+        return self.write_page(HEADER_PAGE, out);
+    }
+
+    fn read_node(&self, page: PageId) -> Result<Node<K, V>, BTreeError> {
+        let mut buffer = vec![0; self.pager.page_size()];
+        self.pager.read_page(page, &mut buffer)?;
+        let mut input = buffer.as_slice();
+
+        let kind = u8::decode(&mut input)?;
+        if kind != FOLHA && kind != INTERNO {
+            return Err(FormatError::InvalidMarker(kind).into());
+        }
+
+        let keys = u16::decode(&mut input)? as usize;
+        let mut entries = Vec::with_capacity(keys);
+        for _ in 0..keys {
+            entries.push((K::decode(&mut input)?, V::decode(&mut input)?));
+        }
+
+        let mut children = Vec::new();
+        if kind == INTERNO {
+            for _ in 0..=keys {
+                children.push(u64::decode(&mut input)?);
+            }
+        }
+
+        return Ok(Node { entries, children });
+    }
+
+    fn write_node(&mut self, page: PageId, node: &Node<K, V>) -> Result<(), BTreeError> {
+        let mut out = Vec::with_capacity(self.pager.page_size());
+        out.push(if node.is_leaf() { FOLHA } else { INTERNO });
+        (node.entries.len() as u16).encode(&mut out)?;
+        for (key, value) in &node.entries {
+            key.encode(&mut out)?;
+            value.encode(&mut out)?;
+        }
+        for child in &node.children {
+            child.encode(&mut out)?;
+        }
+
+        return self.write_page(page, out);
+    }
+
+    //  Completa out com zeros até o tamanho da página
+    fn write_page(&mut self, page: PageId, mut out: Vec<u8>) -> Result<(), BTreeError> {
+        debug_assert!(out.len() <= self.pager.page_size(), "conteúdo maior que a página");
+        out.resize(self.pager.page_size(), 0);
+        self.pager.write_page(page, &out)?;
+        return Ok(());
+    }
+
+    //  Reaproveita uma página livre, ou cria uma nova
+    fn allocate_page(&mut self) -> Result<PageId, BTreeError> {
+        let Some(page) = self.free else {
+            return Ok(self.pager.allocate()?);
+        };
+
+        let mut buffer = vec![0; self.pager.page_size()];
+        self.pager.read_page(page, &mut buffer)?;
+        let mut input = buffer.as_slice();
+
+        let kind = u8::decode(&mut input)?;
+        if kind != PAGINA_LIVRE {
+            return Err(FormatError::InvalidMarker(kind).into());
+        }
+        self.free = non_zero(u64::decode(&mut input)?);
+
+        return Ok(page);
+    }
+
+    //  Coloca a página no início da lista de páginas livres
+    fn free_page(&mut self, page: PageId) -> Result<(), BTreeError> {
+        let mut out = vec![PAGINA_LIVRE];
+        self.free.unwrap_or(0).encode(&mut out)?;
+        self.write_page(page, out)?;
+
+        self.free = Some(page);
+        return Ok(());
+    }
+}
+
+fn non_zero(page: PageId) -> Option<PageId> {
+    if page == 0 { None } else { Some(page) }
+}
+
+fn encoded_len<K: BinaryValue, V: BinaryValue>(key: &K, value: &V) -> Result<usize, FormatError> {
+    let mut out = Vec::new();
+    key.encode(&mut out)?;
+    value.encode(&mut out)?;
+    Ok(out.len())
+}
+
+//  Com entradas desse tamanho, um node com m - 1 chaves e m filhos cabe na página
+fn max_entry_size(page_size: usize, order: usize) -> usize {
+    page_size.saturating_sub(NODE_HEADER_LEN + order * CHILD_LEN) / (order - 1)
+}
+
+fn check_order(page_size: usize, order: usize) -> Result<(), BTreeError> {
+    if order < 3 || order - 1 > u16::MAX as usize {
+        return Err(BTreeError::InvalidOrder(order));
+    }
+    if page_size < HEADER_LEN || max_entry_size(page_size, order) == 0 {
+        return Err(BTreeError::PageTooSmall { page_size, order });
+    }
+    return Ok(());
+}
+
+//  Node no caminho atual: os pares que faltam e os filhos.
+//  next é o filho visitado depois do próximo par
+struct Frame<K, V> {
+    entries: std::vec::IntoIter<(K, V)>,
+    children: Vec<PageId>,
+    next: usize,
+}
+
+/// Iterador em ordem crescente de chave, criado por iter() e range()
+pub struct Range<'a, K, V, P: Pager, R> {
+    tree: &'a BTree<K, V, P>,
+    stack: Vec<Frame<K, V>>,
+    range: R,
+    started: bool,
+    done: bool,
+}
+
+impl<K: Ord + BinaryValue, V: BinaryValue, P: Pager, R: RangeBounds<K>> Range<'_, K, V, P, R> {
+    //  Desce de page até uma folha, empilhando os nodes. Com from_start,
+    //  pula em cada node as chaves antes do início do range
+    fn descend(&mut self, mut page: PageId, from_start: bool) -> Result<(), BTreeError> {
+        loop {
+            let mut node = self.tree.read_node(page)?;
+
+            let i = match (from_start, self.range.start_bound()) {
+                (true, Bound::Included(start)) => node.entries.partition_point(|(k, _)| k < start),
+                (true, Bound::Excluded(start)) => node.entries.partition_point(|(k, _)| k <= start),
+                _ => 0,
+            };
+            node.entries.drain(..i);
+
+            let child = node.children.get(i).copied();
+            self.stack.push(Frame { entries: node.entries.into_iter(), children: node.children, next: i + 1 });
+
+            match child {
+                Some(child) => page = child,
+                None => return Ok(()),
+            }
+        }
+    }
+}
+
+impl<K: Ord + BinaryValue, V: BinaryValue, P: Pager, R: RangeBounds<K>> Iterator for Range<'_, K, V, P, R> {
+    type Item = Result<(K, V), BTreeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {return None;}
+
+        if !self.started {
+            self.started = true;
+            if let Some(root) = self.tree.root
+                && let Err(e) = self.descend(root, true)
+            {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some((key, value)) = frame.entries.next() else {
+                self.stack.pop();
+                continue;
+            };
+
+            //  começou dentro do range, sair dele significa que acabou
+            if !self.range.contains(&key) {
+                self.done = true;
+                return None;
+            }
+
+            let child = frame.children.get(frame.next).copied();
+            frame.next += 1;
+            if let Some(child) = child
+                && let Err(e) = self.descend(child, false)
+            {
+                self.done = true;
+                return Some(Err(e));
+            }
+
+            return Some(Ok((key, value)));
+        }
+    }
+}
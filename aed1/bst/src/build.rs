@@ -0,0 +1,34 @@
+/*
+ *  Construção de BSTs a partir de coleções.
+ *  Usa apenas a interface pública, então vale para os dois backends.
+*/
+
+use crate::BST;
+
+impl<T: PartialOrd> BST<T> {
+    /// Cria uma BST balanceada a partir de valores em ordem crescente <br>
+    /// A mediana de cada intervalo é inserida antes das duas metades,
+    /// resultando na menor altura possível. Valores fora de ordem ainda
+    /// são inseridos corretamente, mas a árvore pode não ficar balanceada,
+    /// e valores repetidos são ignorados
+    pub fn from_sorted(values: Vec<T>) -> BST<T> {
+        let mut values: Vec<Option<T>> = values.into_iter().map(Some).collect();
+        let mut bst = BST::new();
+
+        BST::insert_medians(&mut bst, &mut values);
+        return bst;
+    }
+
+    fn insert_medians(bst: &mut BST<T>, values: &mut [Option<T>]) {
+        if values.is_empty() {return;}
+
+        let middle = values.len() / 2;
+        if let Some(value) = values[middle].take() {
+            bst.insert(value);
+        }
+
+        let (left, right) = values.split_at_mut(middle);
+        BST::insert_medians(bst, left);
+        BST::insert_medians(bst, &mut right[1..]);
+    }
+}
@@ -0,0 +1,238 @@
+/*
+ *  Cursores permitem percorrer a árvore sem expor os NonNull
+ *  usados internamente pela BST.
+ *  Um cursor aponta para um node ou para "fora da árvore" (None),
+ *  posição alcançada ao passar do último/primeiro elemento.
+ *  Assim como no LinkedList da std, avançar a partir de "fora da
+ *  árvore" volta para o começo (ou para o fim, ao retroceder).
+*/
+
+use std::{cmp::Ordering, marker::PhantomData, ptr::NonNull};
+
+use crate::binary_search_tree::{Node, BST};
+
+/// Cursor somente leitura sobre uma BST
+pub struct Cursor<'a, T: PartialOrd> {
+    root: Option<NonNull<Node<T>>>,
+    node: Option<NonNull<Node<T>>>,
+    _tree: PhantomData<&'a BST<T>>,
+}
+
+/// Cursor capaz de remover elementos da BST
+pub struct CursorMut<'a, T: PartialOrd> {
+    tree: &'a mut BST<T>,
+    node: Option<NonNull<Node<T>>>,
+}
+
+//  Cursor se comporta como um &BST<T> e CursorMut como um &mut BST<T>
+unsafe impl<T: PartialOrd + Sync> Send for Cursor<'_, T> {}
+unsafe impl<T: PartialOrd + Sync> Sync for Cursor<'_, T> {}
+unsafe impl<T: PartialOrd + Send> Send for CursorMut<'_, T> {}
+unsafe impl<T: PartialOrd + Sync> Sync for CursorMut<'_, T> {}
+
+//  Implementado manualmente pois o derive exigiria T: Clone
+impl<T: PartialOrd> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: PartialOrd> Copy for Cursor<'_, T> {}
+
+impl<'a, T: PartialOrd> Cursor<'a, T> {
+    pub(crate) fn new(root: Option<NonNull<Node<T>>>, node: Option<NonNull<Node<T>>>) -> Cursor<'a, T> {
+        Cursor { root, node, _tree: PhantomData }
+    }
+
+    //  Cursor na mesma árvore, apontando para outro node
+    fn at(&self, node: Option<NonNull<Node<T>>>) -> Option<Cursor<'a, T>> {
+        node.map(|n| Cursor::new(self.root, Some(n)))
+    }
+
+    /// Valor do node atual <br>
+    /// **None** se o cursor está fora da árvore
+    pub fn value(&self) -> Option<&'a T> {
+        //  O PhantomData garante que a árvore não é alterada enquanto 'a existir
+        self.node.map(|n| unsafe { &(*n.as_ptr()).value })
+    }
+
+    /// Cursor para o filho esquerdo, se houver
+    pub fn left(&self) -> Option<Cursor<'a, T>> {
+        self.at(unsafe { self.node?.as_ref().left })
+    }
+
+    /// Cursor para o filho direito, se houver
+    pub fn right(&self) -> Option<Cursor<'a, T>> {
+        self.at(unsafe { self.node?.as_ref().right })
+    }
+
+    /// Cursor para o parent, se houver (a root não tem parent)
+    pub fn parent(&self) -> Option<Cursor<'a, T>> {
+        self.at(unsafe { self.node?.as_ref().parent })
+    }
+
+    /// Distância até a root (a root tem profundidade 0)
+    pub fn depth(&self) -> Option<usize> {
+        Some(depth(self.node?))
+    }
+
+    /// Avança para o próximo elemento em ordem crescente
+    pub fn move_next(&mut self) {
+        self.node = match self.node {
+            Some(node) => BST::sucessor(node),
+            None => self.root.map(BST::minimum_helper),
+        };
+    }
+
+    /// Retrocede para o elemento anterior em ordem crescente
+    pub fn move_prev(&mut self) {
+        self.node = match self.node {
+            Some(node) => BST::antecessor(node),
+            None => self.root.map(BST::maximum_helper),
+        };
+    }
+}
+
+impl<'a, T: PartialOrd> CursorMut<'a, T> {
+    pub(crate) fn new(tree: &'a mut BST<T>, node: Option<NonNull<Node<T>>>) -> CursorMut<'a, T> {
+        CursorMut { tree, node }
+    }
+
+    /// Cursor somente leitura na mesma posição
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor::new(self.tree.root, self.node)
+    }
+
+    /// Valor do node atual <br>
+    /// **None** se o cursor está fora da árvore
+    pub fn value(&self) -> Option<&T> {
+        self.node.map(|n| unsafe { &(*n.as_ptr()).value })
+    }
+
+    /// Distância até a root (a root tem profundidade 0)
+    pub fn depth(&self) -> Option<usize> {
+        Some(depth(self.node?))
+    }
+
+    /// Move para o filho esquerdo <br>
+    /// **false** se não há filho esquerdo (o cursor não se move)
+    pub fn move_left(&mut self) -> bool {
+        self.move_to(|n| n.left)
+    }
+
+    /// Move para o filho direito <br>
+    /// **false** se não há filho direito (o cursor não se move)
+    pub fn move_right(&mut self) -> bool {
+        self.move_to(|n| n.right)
+    }
+
+    /// Move para o parent <br>
+    /// **false** se o cursor está na root (o cursor não se move)
+    pub fn move_parent(&mut self) -> bool {
+        self.move_to(|n| n.parent)
+    }
+
+    /// Avança para o próximo elemento em ordem crescente
+    pub fn move_next(&mut self) {
+        self.node = match self.node {
+            Some(node) => BST::sucessor(node),
+            None => self.tree.root.map(BST::minimum_helper),
+        };
+    }
+
+    /// Retrocede para o elemento anterior em ordem crescente
+    pub fn move_prev(&mut self) {
+        self.node = match self.node {
+            Some(node) => BST::antecessor(node),
+            None => self.tree.root.map(BST::maximum_helper),
+        };
+    }
+
+    /// Remove o elemento atual e devolve seu valor <br>
+    /// O cursor passa a apontar para o sucessor do elemento removido
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.node?;
+
+        //  O sucessor continua válido após a remoção: no caso de dois
+        //  filhos ele é apenas religado no lugar do node removido
+        self.node = BST::sucessor(node);
+        return Some(self.tree.delete_node(node));
+    }
+
+    fn move_to(&mut self, f: impl Fn(&Node<T>) -> Option<NonNull<Node<T>>>) -> bool {
+        let Some(node) = self.node else {return false;};
+
+        match f(unsafe { node.as_ref() }) {
+            Some(next) => {
+                self.node = Some(next);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn depth<T: PartialOrd>(mut node: NonNull<Node<T>>) -> usize {
+    let mut depth = 0;
+
+    while let Some(parent) = unsafe { node.as_ref().parent } {
+        node = parent;
+        depth += 1;
+    }
+
+    depth
+}
+
+//  Construtores de cursores
+impl<T: PartialOrd> BST<T> {
+    /// Cursor na root da árvore
+    pub fn cursor_root(&self) -> Cursor<'_, T> {
+        Cursor::new(self.root, self.root)
+    }
+
+    /// Cursor no menor elemento
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor::new(self.root, self.root.map(BST::minimum_helper))
+    }
+
+    /// Cursor no maior elemento
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor::new(self.root, self.root.map(BST::maximum_helper))
+    }
+
+    /// Cursor no elemento igual a value <br>
+    /// O cursor fica fora da árvore se value não for encontrado
+    pub fn cursor_at(&self, value: &T) -> Cursor<'_, T> {
+        Cursor::new(self.root, BST::get_node(self.root, value))
+    }
+
+    //  Cursor no primeiro elemento que não é menor que o alvo de f
+    //  (veja get_by), fora da árvore se não houver
+    pub(crate) fn cursor_lower_bound_by(&self, f: impl FnMut(&T) -> Ordering) -> Cursor<'_, T> {
+        Cursor::new(self.root, self.lower_bound_node_by(f))
+    }
+
+    /// Versão mutável de cursor_root()
+    pub fn cursor_root_mut(&mut self) -> CursorMut<'_, T> {
+        let root = self.root;
+        CursorMut::new(self, root)
+    }
+
+    /// Versão mutável de cursor_front()
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let front = self.root.map(BST::minimum_helper);
+        CursorMut::new(self, front)
+    }
+
+    /// Versão mutável de cursor_back()
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let back = self.root.map(BST::maximum_helper);
+        CursorMut::new(self, back)
+    }
+
+    /// Versão mutável de cursor_at()
+    pub fn cursor_at_mut(&mut self, value: &T) -> CursorMut<'_, T> {
+        let node = BST::get_node(self.root, value);
+        CursorMut::new(self, node)
+    }
+}
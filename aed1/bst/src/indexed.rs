@@ -0,0 +1,243 @@
+/*
+ *  Índice em memória para um arquivo de registros (formato de records.rs).
+ *  A BST guarda apenas pares (chave, offset); o registro é lido do
+ *  arquivo a cada consulta, então o arquivo não precisa caber na memória.
+ *
+ *  Chaves repetidas são permitidas: o offset desempata, e os registros
+ *  com a mesma chave aparecem na ordem em que estão no arquivo.
+ *
+ *  Arquivo de índice (inteiros em little-endian):
+ *      tamanho     u64     tamanho do arquivo de dados quando o índice foi salvo
+ *      árvore              BST<(K, u64)> no formato de binary_format.rs
+*/
+
+use std::{
+    cmp::Ordering,
+    error::Error,
+    fmt,
+    fs::{File, OpenOptions},
+    io::{self, BufReader, Read, Seek, SeekFrom, Write},
+    ops::{Bound, RangeBounds},
+    path::Path,
+};
+
+use crate::{
+    binary_format::{BinaryValue, FormatError},
+    records::{self, Purchase, RecordError, RecordReader},
+    Cursor, BST,
+};
+
+/// Erros do IndexedRecordFile
+#[derive(Debug)]
+pub enum IndexError {
+    Io(io::Error),
+    /// Erro ao ler ou escrever um registro do arquivo de dados
+    Record(RecordError),
+    /// Erro ao ler o arquivo de índice
+    Format(FormatError),
+    /// O arquivo de dados mudou depois que o índice foi salvo
+    StaleIndex { indexed: u64, actual: u64 },
+    /// O índice aponta para um offset sem registro no arquivo de dados
+    MissingRecord { offset: u64 },
+}
+
+impl fmt::Display for IndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexError::Io(e) => write!(f, "erro de E/S: {}", e),
+            IndexError::Record(e) => write!(f, "{}", e),
+            IndexError::Format(e) => write!(f, "índice inválido: {}", e),
+            IndexError::StaleIndex { indexed, actual } => {
+                write!(f, "índice desatualizado: feito para {} bytes, o arquivo tem {}", indexed, actual)
+            }
+            IndexError::MissingRecord { offset } => write!(f, "nenhum registro no offset {}", offset),
+        }
+    }
+}
+
+impl Error for IndexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            IndexError::Io(e) => Some(e),
+            IndexError::Record(e) => Some(e),
+            IndexError::Format(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for IndexError {
+    fn from(e: io::Error) -> Self {
+        IndexError::Io(e)
+    }
+}
+
+impl From<RecordError> for IndexError {
+    fn from(e: RecordError) -> Self {
+        IndexError::Record(e)
+    }
+}
+
+impl From<FormatError> for IndexError {
+    fn from(e: FormatError) -> Self {
+        IndexError::Format(e)
+    }
+}
+
+/// Arquivo de registros com um índice por chave
+pub struct IndexedRecordFile<K: Ord> {
+    file: File,
+    index: BST<(K, u64)>,
+    len: u64,
+    key: fn(&Purchase) -> K,
+}
+
+impl<K: Ord> IndexedRecordFile<K> {
+
+    /// Abre (ou cria) o arquivo de dados e monta o índice lendo-o inteiro <br>
+    /// key extrai a chave de cada registro
+    pub fn open(path: impl AsRef<Path>, key: fn(&Purchase) -> K) -> Result<IndexedRecordFile<K>, IndexError> {
+        let file = open_data(path.as_ref())?;
+
+        let mut entries = Vec::new();
+        for result in RecordReader::new(BufReader::new(&file)) {
+            let (offset, record) = result?;
+            entries.push((key(&record), offset));
+        }
+        let len = file.metadata()?.len();
+
+        //  offsets já estão em ordem crescente, o sort estável mantém isso
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        return Ok(IndexedRecordFile { file, index: BST::from_sorted(entries), len, key });
+    }
+
+    /// Quantidade de registros indexados
+    pub fn len(&self) -> usize {
+        self.index.total_len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// O primeiro registro (no arquivo) com a chave key
+    pub fn get(&self, key: &K) -> Result<Option<Purchase>, IndexError> {
+        self.range((Bound::Included(key), Bound::Included(key))).next().transpose()
+    }
+
+    /// Todos os registros com a chave key, na ordem do arquivo
+    pub fn get_all(&self, key: &K) -> Result<Vec<Purchase>, IndexError> {
+        self.range((Bound::Included(key), Bound::Included(key))).collect()
+    }
+
+    /// Offsets dos registros com chave dentro de range, em ordem de chave
+    pub fn offsets<'a, R: RangeBounds<K> + 'a>(&'a self, range: R) -> impl Iterator<Item = (&'a K, u64)> + 'a {
+        let cursor = match range.start_bound() {
+            Bound::Unbounded => self.index.cursor_front(),
+            //  entre chaves iguais, o alvo fica antes de todas (ou depois, se excluído)
+            Bound::Included(start) => self.index.cursor_lower_bound_by(|e| start.cmp(&e.0).then(Ordering::Less)),
+            Bound::Excluded(start) => self.index.cursor_lower_bound_by(|e| start.cmp(&e.0).then(Ordering::Greater)),
+        };
+
+        Offsets { cursor, range }
+    }
+
+    /// Registros com chave dentro de range, em ordem de chave <br>
+    /// Cada registro é lido do arquivo quando o iterador avança
+    pub fn range<'a, R: RangeBounds<K> + 'a>(&'a self, range: R) -> impl Iterator<Item = Result<Purchase, IndexError>> + 'a {
+        self.offsets(range).map(|(_, offset)| self.read_at(offset))
+    }
+
+    /// Lê o registro que começa em offset
+    pub fn read_at(&self, offset: u64) -> Result<Purchase, IndexError> {
+        //  &File também implementa Read e Seek, então não é preciso &mut self
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(offset))?;
+
+        match RecordReader::starting_at(BufReader::new(file), offset).next() {
+            Some(result) => Ok(result?.1),
+            None => Err(IndexError::MissingRecord { offset }),
+        }
+    }
+
+    /// Escreve record no fim do arquivo e o adiciona ao índice <br>
+    /// Retorna o offset do novo registro
+    pub fn append(&mut self, record: &Purchase) -> Result<u64, IndexError> {
+        let offset = self.len;
+
+        //  monta o registro antes, para não escrever nada se ele for inválido
+        let mut bytes = Vec::new();
+        records::write_record(&mut bytes, record)?;
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(&bytes)?;
+
+        self.len += bytes.len() as u64;
+        self.index.insert(((self.key)(record), offset));
+        return Ok(offset);
+    }
+
+    /// Garante que tudo que foi escrito com append() chegou ao disco
+    pub fn flush(&mut self) -> Result<(), IndexError> {
+        self.file.sync_data()?;
+        return Ok(());
+    }
+}
+
+impl<K: Ord + BinaryValue> IndexedRecordFile<K> {
+
+    /// Abre o arquivo de dados usando um índice salvo com save_index(),
+    /// sem ler os registros <br>
+    /// Falha com StaleIndex se o arquivo de dados mudou de tamanho desde então
+    pub fn open_with_index(path: impl AsRef<Path>, index_path: impl AsRef<Path>, key: fn(&Purchase) -> K) -> Result<IndexedRecordFile<K>, IndexError> {
+        let file = open_data(path.as_ref())?;
+        let len = file.metadata()?.len();
+
+        let mut bytes = Vec::new();
+        File::open(index_path)?.read_to_end(&mut bytes)?;
+
+        let mut input = bytes.as_slice();
+        let indexed = u64::decode(&mut input)?;
+        if indexed != len {
+            return Err(IndexError::StaleIndex { indexed, actual: len });
+        }
+        let index = BST::from_bytes(input)?;
+
+        return Ok(IndexedRecordFile { file, index, len, key });
+    }
+
+    /// Salva o índice, para ser usado depois com open_with_index()
+    pub fn save_index(&self, index_path: impl AsRef<Path>) -> Result<(), IndexError> {
+        let mut bytes = Vec::new();
+        self.len.encode(&mut bytes)?;
+        bytes.extend_from_slice(&self.index.to_bytes()?);
+
+        std::fs::write(index_path, bytes)?;
+        return Ok(());
+    }
+}
+
+fn open_data(path: &Path) -> io::Result<File> {
+    OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)
+}
+
+//  Mesma ideia do Iter de map.rs
+struct Offsets<'a, K: Ord, R> {
+    cursor: Cursor<'a, (K, u64)>,
+    range: R,
+}
+
+impl<'a, K: Ord, R: RangeBounds<K>> Iterator for Offsets<'a, K, R> {
+    type Item = (&'a K, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, offset) = self.cursor.value()?;
+
+        if !self.range.contains(key) {
+            return None;
+        }
+
+        self.cursor.move_next();
+        return Some((key, *offset));
+    }
+}
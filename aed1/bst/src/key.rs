@@ -0,0 +1,55 @@
+/*
+ *  Registros guardados na BST por uma chave, como o Registro de main.rs,
+ *  que é comparado apenas pelo id. Com BstKey, a busca pode ser feita
+ *  direto pela chave, sem montar um registro falso (Registro::with_id).
+ *
+ *  Com a feature "derive", #[derive(BstKey)] gera esta implementação
+ *  e as comparações a partir do campo marcado com #[key].
+*/
+
+use crate::{Cursor, BST};
+
+/// Registro identificado por uma chave <br>
+/// A ordem do registro (PartialOrd) deve ser a mesma da chave
+pub trait BstKey {
+    type Key: Ord;
+
+    fn key(&self) -> &Self::Key;
+}
+
+impl<T: PartialOrd + BstKey> BST<T> {
+    /// Registro com a chave key, se houver
+    pub fn get_by_key(&self, key: &T::Key) -> Option<&T> {
+        self.get_by(|value| key.cmp(value.key()))
+    }
+
+    /// Versão mutável de get_by_key() <br>
+    /// A chave do registro não deve ser alterada
+    pub fn get_by_key_mut(&mut self, key: &T::Key) -> Option<&mut T> {
+        self.get_mut_by(|value| key.cmp(value.key()))
+    }
+
+    pub fn contains_key(&self, key: &T::Key) -> bool {
+        self.get_by_key(key).is_some()
+    }
+
+    /// Remove o registro com a chave key, devolvendo-o
+    pub fn remove_by_key(&mut self, key: &T::Key) -> Option<T> {
+        self.remove_by(|value| key.cmp(value.key()))
+    }
+
+    /// Cursor no registro com a chave key <br>
+    /// O cursor fica fora da árvore se key não for encontrada
+    pub fn cursor_at_key(&self, key: &T::Key) -> Cursor<'_, T> {
+        let cursor = self.cursor_lower_bound_by(|value| key.cmp(value.key()));
+
+        match cursor.value() {
+            Some(value) if value.key() == key => cursor,
+            //  cursor fora da árvore, sem uma segunda busca
+            #[cfg(not(feature = "arena"))]
+            _ => Cursor::new(self.root, None),
+            #[cfg(feature = "arena")]
+            _ => Cursor::new(self, None),
+        }
+    }
+}
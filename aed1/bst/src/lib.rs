@@ -1,3 +1,52 @@
+#![allow(clippy::needless_return)]
+#![cfg_attr(feature = "arena", forbid(unsafe_code))]
+
+//  Backend padrão, com ponteiros NonNull
+#[cfg(not(feature = "arena"))]
 mod binary_search_tree;
+#[cfg(not(feature = "arena"))]
+mod cursor;
+#[cfg(not(feature = "arena"))]
 pub use  binary_search_tree::Node;
-pub use binary_search_tree::BST;
\ No newline at end of file
+#[cfg(not(feature = "arena"))]
+pub use binary_search_tree::BST;
+#[cfg(not(feature = "arena"))]
+pub use cursor::{Cursor, CursorMut};
+
+//  Backend sem unsafe, com os nodes em um Vec (feature "arena")
+#[cfg(feature = "arena")]
+mod arena;
+#[cfg(feature = "arena")]
+mod arena_cursor;
+#[cfg(feature = "arena")]
+pub use arena::{Node, BST};
+#[cfg(feature = "arena")]
+pub use arena_cursor::{Cursor, CursorMut};
+
+mod traits;
+mod build;
+mod show;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod shared;
+pub use shared::SharedBst;
+mod persistent;
+pub use persistent::PersistentBst;
+pub mod binary_format;
+pub use binary_format::{BinaryValue, FormatError};
+pub mod map;
+pub use map::BstMap;
+pub mod records;
+pub mod text_records;
+pub mod indexed;
+pub use indexed::IndexedRecordFile;
+pub mod pager;
+pub use pager::{FilePager, MemoryPager, Pager};
+pub mod btree;
+pub use btree::{BTree, BTreeError};
+mod key;
+pub use key::BstKey;
+#[cfg(feature = "derive")]
+pub use bst_derive::BstKey;
+pub mod observer;
+pub use observer::{BstObserver, Trace};
@@ -0,0 +1,200 @@
+/*
+ *  Mapa ordenado chave -> valor construído sobre a BST.
+ *  Cada node guarda uma Entry, comparada apenas pela chave,
+ *  então o valor pode ser alterado livremente sem quebrar a BST.
+*/
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    ops::RangeBounds,
+};
+
+use crate::{Cursor, BST};
+
+pub(crate) struct Entry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+impl<K: Ord, V> PartialEq for Entry<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> PartialOrd for Entry<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.key.cmp(&other.key))
+    }
+}
+
+impl<K: Clone, V: Clone> Clone for Entry<K, V> {
+    fn clone(&self) -> Self {
+        Entry { key: self.key.clone(), value: self.value.clone() }
+    }
+}
+
+pub struct BstMap<K: Ord, V> {
+    tree: BST<Entry<K, V>>,
+    len: usize,
+}
+
+impl<K: Ord, V> Default for BstMap<K, V> {
+    fn default() -> Self {
+        BstMap::new()
+    }
+}
+
+impl<K: Ord, V> BstMap<K, V> {
+
+    /// Retorna um novo BstMap vazio
+    pub fn new() -> BstMap<K, V> {
+        BstMap { tree: BST::new(), len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Associa value a key <br>
+    /// Retorna o valor anterior, se key já existia
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(old) = self.get_mut(&key) {
+            return Some(std::mem::replace(old, value));
+        }
+
+        self.tree.insert(Entry { key, value });
+        self.len += 1;
+        return None;
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.tree.get_by(|e| key.cmp(&e.key)).map(|e| &e.value)
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.tree.get_mut_by(|e| key.cmp(&e.key)).map(|e| &mut e.value)
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Remove key, retornando seu valor se existia
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.tree.remove_by(|e| key.cmp(&e.key))?;
+        self.len -= 1;
+        return Some(entry.value);
+    }
+
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.tree.minimum().map(|e| (&e.key, &e.value))
+    }
+
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.tree.maximum().map(|e| (&e.key, &e.value))
+    }
+
+    pub fn clear(&mut self) {
+        self.tree.clear();
+        self.len = 0;
+    }
+
+    /// Pares em ordem crescente de chave
+    pub fn iter(&self) -> Iter<'_, K, V, std::ops::RangeFull> {
+        self.range(..)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Pares com chave dentro de range, em ordem crescente
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> Iter<'_, K, V, R> {
+        use std::ops::Bound;
+
+        let cursor = match range.start_bound() {
+            Bound::Unbounded => self.tree.cursor_front(),
+            Bound::Included(start) => self.tree.cursor_lower_bound_by(|e| start.cmp(&e.key)),
+            Bound::Excluded(start) => self.tree.cursor_lower_bound_by(|e| {
+                //  trata a chave igual ao início como menor, pulando-a
+                start.cmp(&e.key).then(Ordering::Greater)
+            }),
+        };
+
+        Iter { cursor, range }
+    }
+}
+
+/// Iterador em ordem crescente de chave, criado por iter() e range()
+pub struct Iter<'a, K: Ord, V, R> {
+    cursor: Cursor<'a, Entry<K, V>>,
+    range: R,
+}
+
+impl<'a, K: Ord, V, R: RangeBounds<K>> Iterator for Iter<'a, K, V, R> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.cursor.value()?;
+
+        //  o cursor começa dentro do range, sair dele significa que acabou
+        if !self.range.contains(&entry.key) {
+            return None;
+        }
+
+        self.cursor.move_next();
+        return Some((&entry.key, &entry.value));
+    }
+}
+
+impl<K: Ord + Clone, V: Clone> Clone for BstMap<K, V> {
+    fn clone(&self) -> Self {
+        BstMap { tree: self.tree.clone(), len: self.len }
+    }
+}
+
+//  Formato de mapa, em ordem crescente: {1: "a", 2: "b"}
+impl<K: Ord + Debug, V: Debug> Debug for BstMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+//  Monta a árvore balanceada de uma vez. Assim como no BTreeMap,
+//  se uma chave se repete, o último valor é o que fica
+impl<K: Ord, V> FromIterator<(K, V)> for BstMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut entries: Vec<Entry<K, V>> = iter.into_iter().map(|(key, value)| Entry { key, value }).collect();
+
+        //  sort estável: entre chaves iguais, a última inserida fica por último
+        entries.sort_by(|a, b| a.key.cmp(&b.key));
+        let mut deduped: Vec<Entry<K, V>> = Vec::with_capacity(entries.len());
+        for entry in entries {
+            match deduped.last_mut() {
+                Some(last) if last.key == entry.key => *last = entry,
+                _ => deduped.push(entry),
+            }
+        }
+
+        let len = deduped.len();
+        BstMap { tree: BST::from_sorted(deduped), len }
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BstMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ *  Ganchos para acompanhar, passo a passo, o que insert e delete
+ *  fazem na árvore: cada comparação, cada descida para um filho,
+ *  onde o novo node foi ligado e como o sucessor tomou o lugar do
+ *  node removido. Útil para visualizar os algoritmos em aula.
+ *
+ *      let mut trace = Trace::new();
+ *      bst.insert_traced(5, &mut trace);
+ *      for event in trace.events() { ... }
+ *
+ *  insert e delete usam o observer (), que não faz nada e é
+ *  eliminado pelo compilador, então não há custo sem o trace.
+ *  A BST não é balanceada, então não há rotações para observar.
+*/
+
+use std::cmp::Ordering;
+
+/// Lado de um filho em relação ao parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// Recebe os passos de BST::insert_traced() e BST::delete_traced() <br>
+/// Todos os métodos têm implementação vazia, basta sobrescrever os de interesse
+pub trait BstObserver<T> {
+    /// value foi comparado com o valor de um node
+    fn compare(&mut self, _value: &T, _node: &T, _ordering: Ordering) {}
+
+    /// A busca desceu de node para seu filho em direction
+    fn descend(&mut self, _node: &T, _direction: Direction) {}
+
+    /// Um novo node com value foi ligado à árvore, como filho de parent
+    /// em direction, ou como root (None)
+    fn link(&mut self, _value: &T, _parent: Option<(&T, Direction)>) {}
+
+    /// Na remoção de node, que tem dois filhos, sucessor foi escolhido
+    /// para ocupar o seu lugar
+    fn sucessor(&mut self, _node: &T, _sucessor: &T) {}
+
+    /// A sub-árvore de removed foi substituída pela de replacement
+    /// (None: removed era uma folha)
+    fn transplant(&mut self, _removed: &T, _replacement: Option<&T>) {}
+}
+
+/// Observer que não faz nada, usado por insert() e delete()
+impl<T> BstObserver<T> for () {}
+
+/// Um passo registrado por Trace
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<T> {
+    Compare { value: T, node: T, ordering: Ordering },
+    Descend { node: T, direction: Direction },
+    Link { value: T, parent: Option<(T, Direction)> },
+    Sucessor { node: T, sucessor: T },
+    Transplant { removed: T, replacement: Option<T> },
+}
+
+/// Observer que guarda uma cópia de cada passo, em ordem,
+/// para ser reproduzida depois
+#[derive(Debug, Clone)]
+pub struct Trace<T> {
+    events: Vec<Event<T>>,
+}
+
+impl<T> Default for Trace<T> {
+    fn default() -> Self {
+        Trace::new()
+    }
+}
+
+impl<T> Trace<T> {
+    pub fn new() -> Trace<T> {
+        Trace { events: Vec::new() }
+    }
+
+    pub fn events(&self) -> &[Event<T>] {
+        &self.events
+    }
+
+    /// Devolve os passos registrados, deixando o trace vazio
+    pub fn take(&mut self) -> Vec<Event<T>> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+impl<T: Clone> BstObserver<T> for Trace<T> {
+    fn compare(&mut self, value: &T, node: &T, ordering: Ordering) {
+        self.events.push(Event::Compare { value: value.clone(), node: node.clone(), ordering });
+    }
+
+    fn descend(&mut self, node: &T, direction: Direction) {
+        self.events.push(Event::Descend { node: node.clone(), direction });
+    }
+
+    fn link(&mut self, value: &T, parent: Option<(&T, Direction)>) {
+        let parent = parent.map(|(p, d)| (p.clone(), d));
+        self.events.push(Event::Link { value: value.clone(), parent });
+    }
+
+    fn sucessor(&mut self, node: &T, sucessor: &T) {
+        self.events.push(Event::Sucessor { node: node.clone(), sucessor: sucessor.clone() });
+    }
+
+    fn transplant(&mut self, removed: &T, replacement: Option<&T>) {
+        self.events.push(Event::Transplant { removed: removed.clone(), replacement: replacement.cloned() });
+    }
+}
+
+//  Mesmo critério de comparação da BST: sem ordem definida conta como igual
+pub(crate) fn ordering<T: PartialOrd>(value: &T, node: &T) -> Ordering {
+    if value < node {
+        Ordering::Less
+    } else if value > node {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
@@ -0,0 +1,165 @@
+/*
+ *  Armazenamento em páginas de tamanho fixo, usado pela BTree.
+ *  O Pager só lê, escreve e cria páginas; quem decide o que vai
+ *  em cada página (e reaproveita páginas livres) é a BTree.
+*/
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+/// Número de uma página, a partir de 0
+pub type PageId = u64;
+
+/// Conjunto de páginas de tamanho fixo
+pub trait Pager {
+    /// Tamanho de todas as páginas, em bytes
+    fn page_size(&self) -> usize;
+
+    /// Quantidade de páginas já criadas
+    fn page_count(&self) -> u64;
+
+    /// Lê a página id para buffer (que tem page_size() bytes)
+    fn read_page(&self, id: PageId, buffer: &mut [u8]) -> io::Result<()>;
+
+    /// Escreve data (com page_size() bytes) na página id, que já deve existir
+    fn write_page(&mut self, id: PageId, data: &[u8]) -> io::Result<()>;
+
+    /// Cria uma página zerada no fim e retorna seu id
+    fn allocate(&mut self) -> io::Result<PageId>;
+
+    /// Garante que as escritas chegaram ao armazenamento
+    fn sync(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn missing_page(id: PageId) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, format!("página {} não existe", id))
+}
+
+fn check_size(data: &[u8], page_size: usize) -> io::Result<()> {
+    if data.len() != page_size {
+        let msg = format!("página com {} bytes, esperado {}", data.len(), page_size);
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, msg));
+    }
+    return Ok(());
+}
+
+/// Páginas mantidas em memória, útil para testes
+pub struct MemoryPager {
+    page_size: usize,
+    pages: Vec<Box<[u8]>>,
+}
+
+impl MemoryPager {
+    pub fn new(page_size: usize) -> MemoryPager {
+        MemoryPager { page_size, pages: Vec::new() }
+    }
+}
+
+impl Pager for MemoryPager {
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn page_count(&self) -> u64 {
+        self.pages.len() as u64
+    }
+
+    fn read_page(&self, id: PageId, buffer: &mut [u8]) -> io::Result<()> {
+        check_size(buffer, self.page_size)?;
+        let page = self.pages.get(id as usize).ok_or_else(|| missing_page(id))?;
+        buffer.copy_from_slice(page);
+        return Ok(());
+    }
+
+    fn write_page(&mut self, id: PageId, data: &[u8]) -> io::Result<()> {
+        check_size(data, self.page_size)?;
+        let page = self.pages.get_mut(id as usize).ok_or_else(|| missing_page(id))?;
+        page.copy_from_slice(data);
+        return Ok(());
+    }
+
+    fn allocate(&mut self) -> io::Result<PageId> {
+        self.pages.push(vec![0; self.page_size].into_boxed_slice());
+        return Ok(self.pages.len() as u64 - 1);
+    }
+}
+
+/// Páginas em um arquivo: a página id fica no offset id * page_size
+pub struct FilePager {
+    file: File,
+    page_size: usize,
+    pages: u64,
+}
+
+impl FilePager {
+    /// Abre (ou cria) o arquivo de páginas <br>
+    /// Falha se o tamanho do arquivo não é múltiplo de page_size
+    pub fn open(path: impl AsRef<Path>, page_size: usize) -> io::Result<FilePager> {
+        if page_size == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "tamanho de página 0"));
+        }
+
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open(path)?;
+        let len = file.metadata()?.len();
+        if len % page_size as u64 != 0 {
+            let msg = format!("arquivo com {} bytes não é múltiplo do tamanho de página {}", len, page_size);
+            return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+        }
+
+        return Ok(FilePager { file, page_size, pages: len / page_size as u64 });
+    }
+
+    fn offset(&self, id: PageId) -> u64 {
+        id * self.page_size as u64
+    }
+}
+
+impl Pager for FilePager {
+    fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    fn page_count(&self) -> u64 {
+        self.pages
+    }
+
+    fn read_page(&self, id: PageId, buffer: &mut [u8]) -> io::Result<()> {
+        check_size(buffer, self.page_size)?;
+        if id >= self.pages {
+            return Err(missing_page(id));
+        }
+
+        //  &File também implementa Read e Seek
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(self.offset(id)))?;
+        file.read_exact(buffer)
+    }
+
+    fn write_page(&mut self, id: PageId, data: &[u8]) -> io::Result<()> {
+        check_size(data, self.page_size)?;
+        if id >= self.pages {
+            return Err(missing_page(id));
+        }
+
+        self.file.seek(SeekFrom::Start(self.offset(id)))?;
+        self.file.write_all(data)
+    }
+
+    fn allocate(&mut self) -> io::Result<PageId> {
+        let id = self.pages;
+        self.file.seek(SeekFrom::Start(self.offset(id)))?;
+        self.file.write_all(&vec![0; self.page_size])?;
+
+        self.pages += 1;
+        return Ok(id);
+    }
+
+    fn sync(&mut self) -> io::Result<()> {
+        self.file.sync_data()
+    }
+}
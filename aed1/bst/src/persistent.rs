@@ -0,0 +1,331 @@
+/*
+ *  BST persistente (imutável).
+ *  insert e delete não alteram a árvore: retornam uma nova versão.
+ *  Apenas os nodes no caminho da root até o node alterado são
+ *  copiados (path copying), todas as outras sub-árvores são
+ *  compartilhadas entre as versões por meio de Arc.
+ *  Guardar uma versão antiga custa apenas um Arc::clone da root,
+ *  o que torna undo/redo baratos.
+*/
+
+use std::{cmp, fmt::Display, sync::Arc};
+
+use crate::{show, Cursor, BST};
+
+type Link<T> = Option<Arc<PersistentNode<T>>>;
+
+struct PersistentNode<T> {
+    value: T,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T: Clone> PersistentNode<T> {
+    //  Cópia do node com novos filhos, o valor é clonado
+    fn with_children(&self, left: Link<T>, right: Link<T>) -> Arc<PersistentNode<T>> {
+        Arc::new(PersistentNode { value: self.value.clone(), left, right })
+    }
+}
+
+pub struct PersistentBst<T: PartialOrd> {
+    root: Link<T>,
+    len: usize,
+}
+
+//  Clonar uma versão apenas incrementa o contador do Arc da root
+impl<T: PartialOrd> Clone for PersistentBst<T> {
+    fn clone(&self) -> Self {
+        PersistentBst { root: self.root.clone(), len: self.len }
+    }
+}
+
+impl<T: PartialOrd> Default for PersistentBst<T> {
+    fn default() -> Self {
+        PersistentBst::new()
+    }
+}
+
+//  Libera apenas os nodes que não são compartilhados com outras versões
+//  Iterativo para não estourar a pilha em árvores degeneradas
+impl<T: PartialOrd> Drop for PersistentBst<T> {
+    fn drop(&mut self) {
+        let mut pending: Vec<Arc<PersistentNode<T>>> = self.root.take().into_iter().collect();
+
+        while let Some(node) = pending.pop() {
+            //  se outra versão ainda usa o node, ele (e seus filhos) fica
+            if let Ok(mut node) = Arc::try_unwrap(node) {
+                pending.extend(node.left.take());
+                pending.extend(node.right.take());
+            }
+        }
+    }
+}
+
+//  Consultas, com a mesma interface da BST
+impl<T: PartialOrd> PersistentBst<T> {
+
+    /// Retorna uma nova PersistentBst vazia
+    pub fn new() -> PersistentBst<T> {
+        PersistentBst { root: None, len: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn search(&self, value: &T) -> bool {
+        self.get(value).is_some()
+    }
+
+    pub fn get(&self, value: &T) -> Option<&T> {
+        let mut current = self.root.as_ref();
+
+        while let Some(node) = current {
+            if value < &node.value {
+                current = node.left.as_ref();
+            }
+            else if value > &node.value {
+                current = node.right.as_ref();
+            }
+            else {
+                return Some(&node.value);
+            }
+        }
+
+        return None;
+    }
+
+    pub fn minimum(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = node.left.as_ref() {
+            node = left;
+        }
+        return Some(&node.value);
+    }
+
+    pub fn maximum(&self) -> Option<&T> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = node.right.as_ref() {
+            node = right;
+        }
+        return Some(&node.value);
+    }
+
+    pub fn total_len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_balanced(&self) -> bool {
+        is_balanced_recursive(&self.root)
+    }
+
+    pub fn is_bst(&self) -> bool {
+        //  em ordem, cada valor precisa ser maior que o anterior
+        self.walk().windows(2).all(|pair| pair[0] < pair[1])
+    }
+
+    pub fn leafs_len(&self) -> usize {
+        leafs_len_recursive(&self.root)
+    }
+
+    pub fn altura(&self) -> usize {
+        altura_recursiva(&self.root)
+    }
+
+    //  walk in order
+    pub fn walk(&self) -> Vec<&T> {
+        let mut vec = Vec::with_capacity(self.len);
+        walk_recursive(&self.root, &mut vec, false);
+        return vec;
+    }
+
+    pub fn walk_reverse(&self) -> Vec<&T> {
+        let mut vec = Vec::with_capacity(self.len);
+        walk_recursive(&self.root, &mut vec, true);
+        vec
+    }
+
+    /// Valores entre from e to, inclusive, em ordem <br>
+    /// Só desce nas subárvores que podem ter valores no intervalo,
+    /// então custa O(altura + k) em vez de percorrer a árvore toda
+    pub fn walk_range(&self, from: &T, to: &T) -> Vec<&T> {
+        let mut vec = Vec::new();
+        walk_range_recursive(&self.root, from, to, &mut vec);
+        return vec;
+    }
+
+    /// **true** se as duas versões são a mesma árvore
+    /// (compartilham a root), sem comparar os valores
+    pub fn ptr_eq(&self, other: &PersistentBst<T>) -> bool {
+        match (&self.root, &other.root) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+//  Alterações, que retornam uma nova versão
+//  T: Clone pois os nodes do caminho alterado são copiados
+impl<T: PartialOrd + Clone> PersistentBst<T> {
+
+    /// Nova versão com value adicionado <br>
+    /// Se value já existia, a nova versão é a própria árvore atual
+    pub fn insert(&self, value: T) -> PersistentBst<T> {
+        match insert_recursive(&self.root, value) {
+            Some(root) => PersistentBst { root: Some(root), len: self.len + 1 },
+            None => self.clone(),
+        }
+    }
+
+    /// Nova versão sem value <br>
+    /// Se value não existia, a nova versão é a própria árvore atual
+    pub fn delete(&self, value: &T) -> PersistentBst<T> {
+        match delete_recursive(&self.root, value) {
+            Some(root) => PersistentBst { root, len: self.len - 1 },
+            None => self.clone(),
+        }
+    }
+}
+
+//  Conversões com a BST, preservando o formato da árvore
+impl<T: PartialOrd + Clone> From<&BST<T>> for PersistentBst<T> {
+    fn from(bst: &BST<T>) -> Self {
+        let root = if bst.is_empty() { None } else { from_cursor(Some(bst.cursor_root())) };
+        PersistentBst { root, len: bst.total_len() }
+    }
+}
+
+//  Inserir em pré-ordem reproduz o formato (veja o Clone da BST)
+impl<T: PartialOrd + Clone> From<&PersistentBst<T>> for BST<T> {
+    fn from(tree: &PersistentBst<T>) -> Self {
+        let mut bst = BST::new();
+        let mut pending: Vec<&Arc<PersistentNode<T>>> = tree.root.iter().collect();
+
+        while let Some(node) = pending.pop() {
+            bst.insert(node.value.clone());
+
+            pending.extend(&node.right);
+            pending.extend(&node.left);
+        }
+
+        return bst;
+    }
+}
+
+fn from_cursor<T: PartialOrd + Clone>(cursor: Option<Cursor<'_, T>>) -> Link<T> {
+    let cursor = cursor?;
+    let value = cursor.value()?.clone();
+
+    Some(Arc::new(PersistentNode { value, left: from_cursor(cursor.left()), right: from_cursor(cursor.right()) }))
+}
+
+//  Retorna a nova root da sub-árvore, ou None se o valor já existia
+fn insert_recursive<T: PartialOrd + Clone>(node: &Link<T>, value: T) -> Option<Arc<PersistentNode<T>>> {
+    let Some(node) = node else {
+        return Some(Arc::new(PersistentNode { value, left: None, right: None }));
+    };
+
+    if value < node.value {
+        let left = insert_recursive(&node.left, value)?;
+        return Some(node.with_children(Some(left), node.right.clone()));
+    }
+    else if value > node.value {
+        let right = insert_recursive(&node.right, value)?;
+        return Some(node.with_children(node.left.clone(), Some(right)));
+    }
+
+    return None;
+}
+
+//  Retorna a nova root da sub-árvore, ou None se o valor não foi encontrado
+fn delete_recursive<T: PartialOrd + Clone>(node: &Link<T>, value: &T) -> Option<Link<T>> {
+    let node = node.as_ref()?;
+
+    if value < &node.value {
+        let left = delete_recursive(&node.left, value)?;
+        return Some(Some(node.with_children(left, node.right.clone())));
+    }
+    else if value > &node.value {
+        let right = delete_recursive(&node.right, value)?;
+        return Some(Some(node.with_children(node.left.clone(), right)));
+    }
+
+    //  mesmo critério da BST: com dois filhos, o sucessor ocupa o lugar do node
+    return match (&node.left, &node.right) {
+        (None, right) => Some(right.clone()),
+        (left, None) => Some(left.clone()),
+        (Some(left), Some(right)) => {
+            let (sucessor, right) = remove_minimum(right);
+            Some(Some(Arc::new(PersistentNode { value: sucessor, left: Some(left.clone()), right })))
+        }
+    };
+}
+
+//  Remove o menor valor da sub-árvore, retornando-o junto da nova sub-árvore
+fn remove_minimum<T: Clone>(node: &Arc<PersistentNode<T>>) -> (T, Link<T>) {
+    match &node.left {
+        None => (node.value.clone(), node.right.clone()),
+        Some(left) => {
+            let (minimum, left) = remove_minimum(left);
+            (minimum, Some(node.with_children(left, node.right.clone())))
+        }
+    }
+}
+
+fn walk_recursive<'a, T>(node: &'a Link<T>, vec: &mut Vec<&'a T>, reverse: bool) {
+    if let Some(node) = node {
+        let (first, second) = if reverse { (&node.right, &node.left) } else { (&node.left, &node.right) };
+        walk_recursive(first, vec, reverse);
+        vec.push(&node.value);
+        walk_recursive(second, vec, reverse);
+    }
+}
+
+fn walk_range_recursive<'a, T: PartialOrd>(node: &'a Link<T>, from: &T, to: &T, vec: &mut Vec<&'a T>) {
+    let Some(node) = node else {return;};
+
+    //  à esquerda só há valores menores, e à direita só maiores
+    if node.value > *from {
+        walk_range_recursive(&node.left, from, to, vec);
+    }
+    if node.value >= *from && node.value <= *to {
+        vec.push(&node.value);
+    }
+    if node.value < *to {
+        walk_range_recursive(&node.right, from, to, vec);
+    }
+}
+
+fn altura_recursiva<T>(node: &Link<T>) -> usize {
+    let Some(node) = node else {return 0;};
+    return cmp::max(altura_recursiva(&node.left), altura_recursiva(&node.right)) + 1;
+}
+
+fn is_balanced_recursive<T>(node: &Link<T>) -> bool {
+    let Some(node) = node else {return true;};
+
+    let height_balanced =
+        (altura_recursiva(&node.left) as i32 -
+        altura_recursiva(&node.right) as i32).abs() <= 1;
+
+    return is_balanced_recursive(&node.left) && is_balanced_recursive(&node.right) && height_balanced;
+}
+
+fn leafs_len_recursive<T>(node: &Link<T>) -> usize {
+    let Some(node) = node else {return 0;};
+
+    if node.left.is_none() && node.right.is_none() {
+        return 1;
+    }
+    return leafs_len_recursive(&node.left) + leafs_len_recursive(&node.right);
+}
+
+//  Print da árvore, no mesmo formato de BST::show_tree
+impl<T: Display + PartialOrd> PersistentBst<T> {
+    pub fn show_tree(&self) {
+        show::show_tree(self.root.as_deref(), |node: &PersistentNode<T>| {
+            (&node.value, node.left.as_deref(), node.right.as_deref())
+        });
+    }
+}
@@ -0,0 +1,256 @@
+/*
+ *  Arquivos de registros de compras, no formato de ORI/atv2 (compras.bin):
+ *  cada registro é 1 byte com o tamanho, seguido do texto "nome|qtd|valor".
+ *  Não há separador entre registros, o byte de tamanho indica onde
+ *  o próximo começa.
+ *      0f "manteiga|1|4.50" 0a "pao|1|7.99" ...
+ *  O valor é escrito com 2 casas decimais, como no gera_arquivo.c
+*/
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, Read, Write},
+};
+
+use crate::{map::BstMap, BST};
+
+/// Um registro de compra
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct Purchase {
+    pub name: String,
+    pub qty: u32,
+    pub price: f64,
+}
+
+impl Purchase {
+    pub fn new(name: &str, qty: u32, price: f64) -> Purchase {
+        Purchase { name: name.to_string(), qty, price }
+    }
+
+    /// Valor total da compra (quantidade * preço)
+    pub fn total(&self) -> f64 {
+        self.qty as f64 * self.price
+    }
+}
+
+//  Mesmo formato do printf de ORI/atv2/main.c
+impl fmt::Display for Purchase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "nome: {} quantidade: {} valor: {:.2}", self.name, self.qty, self.price)
+    }
+}
+
+/// Erros ao ler ou escrever um arquivo de registros <br>
+/// offset é a posição, no arquivo, do byte de tamanho do registro
+#[derive(Debug)]
+pub enum RecordError {
+    Io(io::Error),
+    /// O arquivo acabou no meio de um registro
+    Truncated { offset: u64, expected: usize, found: usize },
+    /// Registro com tamanho 0
+    BadLength { offset: u64, len: usize },
+    /// Registro longo demais para o byte de tamanho (mais de 255 bytes)
+    TooLong { len: usize },
+    /// O registro não tem todos os campos "nome|qtd|valor"
+    MissingField { offset: u64, field: &'static str },
+    /// Um campo não pôde ser interpretado
+    InvalidField { offset: u64, field: &'static str, value: String },
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecordError::Io(e) => write!(f, "erro de E/S: {}", e),
+            RecordError::Truncated { offset, expected, found } => {
+                write!(f, "registro em {} truncado: esperados {} bytes, encontrados {}", offset, expected, found)
+            }
+            RecordError::BadLength { offset, len } => {
+                write!(f, "registro em {} com tamanho inválido: {}", offset, len)
+            }
+            RecordError::TooLong { len } => {
+                write!(f, "registro com {} bytes não cabe no byte de tamanho (máximo 255)", len)
+            }
+            RecordError::MissingField { offset, field } => {
+                write!(f, "registro em {} sem o campo {}", offset, field)
+            }
+            RecordError::InvalidField { offset, field, value } => {
+                write!(f, "registro em {} com {} inválido: {:?}", offset, field, value)
+            }
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RecordError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RecordError {
+    fn from(e: io::Error) -> Self {
+        RecordError::Io(e)
+    }
+}
+
+/// Interpreta o texto "nome|qtd|valor" de um registro <br>
+/// Assim como no main.c, os campos são separados a partir do fim,
+/// então o nome pode conter '|'
+pub fn parse_record(bytes: &[u8], offset: u64) -> Result<Purchase, RecordError> {
+    let text = std::str::from_utf8(bytes).map_err(|_| RecordError::InvalidField {
+        offset,
+        field: "name",
+        value: String::from_utf8_lossy(bytes).into_owned(),
+    })?;
+
+    let mut fields = text.rsplitn(3, '|');
+    let price = fields.next().unwrap_or_default();
+    let qty = fields.next().ok_or(RecordError::MissingField { offset, field: "qty" })?;
+    let name = fields.next().ok_or(RecordError::MissingField { offset, field: "name" })?;
+
+    let invalid = |field, value: &str| RecordError::InvalidField { offset, field, value: value.to_string() };
+
+    let parsed_qty = qty.trim().parse().map_err(|_| invalid("qty", qty))?;
+    let parsed_price = match price.trim().parse::<f64>() {
+        Ok(p) if p.is_finite() && p >= 0.0 => p,
+        _ => return Err(invalid("price", price)),
+    };
+
+    return Ok(Purchase { name: name.to_string(), qty: parsed_qty, price: parsed_price });
+}
+
+/// Texto "nome|qtd|valor" de um registro, sem o byte de tamanho
+pub fn format_record(record: &Purchase) -> String {
+    format!("{}|{}|{:.2}", record.name, record.qty, record.price)
+}
+
+/// Escreve um registro (byte de tamanho + texto) <br>
+/// Retorna quantos bytes foram escritos
+pub fn write_record<W: Write>(w: &mut W, record: &Purchase) -> Result<usize, RecordError> {
+    let text = format_record(record);
+
+    let len = u8::try_from(text.len()).map_err(|_| RecordError::TooLong { len: text.len() })?;
+    w.write_all(&[len])?;
+    w.write_all(text.as_bytes())?;
+
+    return Ok(1 + text.len());
+}
+
+/// Escreve todos os registros, em ordem
+pub fn write_records<'a, W: Write>(mut w: W, records: impl IntoIterator<Item = &'a Purchase>) -> Result<(), RecordError> {
+    for record in records {
+        write_record(&mut w, record)?;
+    }
+    return Ok(());
+}
+
+/// Lê registros um a um, junto com o offset de cada um no arquivo <br>
+/// Para arquivos, envolva o reader em um BufReader
+pub struct RecordReader<R: Read> {
+    reader: R,
+    offset: u64,
+    failed: bool,
+}
+
+impl<R: Read> RecordReader<R> {
+    pub fn new(reader: R) -> RecordReader<R> {
+        RecordReader::starting_at(reader, 0)
+    }
+
+    /// Reader que já está posicionado em offset no arquivo
+    pub fn starting_at(reader: R, offset: u64) -> RecordReader<R> {
+        RecordReader { reader, offset, failed: false }
+    }
+
+    fn read_next(&mut self) -> Result<Option<(u64, Purchase)>, RecordError> {
+        let offset = self.offset;
+
+        let mut len = [0u8; 1];
+        if read_full(&mut self.reader, &mut len)? == 0 {
+            return Ok(None); //     fim do arquivo entre registros
+        }
+        let len = len[0] as usize;
+        if len == 0 {
+            return Err(RecordError::BadLength { offset, len });
+        }
+
+        let mut buffer = vec![0u8; len];
+        let found = read_full(&mut self.reader, &mut buffer)?;
+        if found < len {
+            return Err(RecordError::Truncated { offset, expected: len, found });
+        }
+
+        self.offset += 1 + len as u64;
+        return Ok(Some((offset, parse_record(&buffer, offset)?)));
+    }
+}
+
+//  Como read_exact, mas retorna quantos bytes foram lidos em vez de falhar no EOF
+fn read_full<R: Read>(reader: &mut R, buffer: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buffer.len() {
+        match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    return Ok(filled);
+}
+
+impl<R: Read> Iterator for RecordReader<R> {
+    type Item = Result<(u64, Purchase), RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        //  depois de um erro não é possível saber onde está o próximo registro
+        if self.failed {return None;}
+
+        let result = self.read_next();
+        if result.is_err() {
+            self.failed = true;
+        }
+        return result.transpose();
+    }
+}
+
+/// Lê todos os registros, parando no primeiro erro
+pub fn read_records<R: Read>(reader: R) -> Result<Vec<Purchase>, RecordError> {
+    RecordReader::new(reader).map(|r| r.map(|(_, record)| record)).collect()
+}
+
+/// Carrega os registros em uma BST balanceada, ordenada pela chave
+/// escolhida <br>
+/// Cada registro vai junto com a sua posição na entrada, que desempata
+/// chaves iguais e mantém registros idênticos como nodes separados <br>
+/// K: Ord, pois from_sorted precisa de uma ordem total
+/// (uma chave f64 com NaN deixaria a árvore fora de ordem)
+pub fn load_bst<K: Ord>(records: impl IntoIterator<Item = Purchase>, key: impl Fn(&Purchase) -> K) -> BST<(K, usize, Purchase)> {
+    let mut keyed: Vec<(K, usize, Purchase)> = records.into_iter().enumerate().map(|(i, r)| (key(&r), i, r)).collect();
+    //  sort estável: entre chaves iguais as posições já estão em ordem
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+    return BST::from_sorted(keyed);
+}
+
+/// Carrega os registros em um BstMap pela chave escolhida <br>
+/// Registros com a mesma chave ficam juntos, na ordem original
+pub fn load_map<K: Ord>(records: impl IntoIterator<Item = Purchase>, key: impl Fn(&Purchase) -> K) -> BstMap<K, Vec<Purchase>> {
+    let mut keyed: Vec<(K, Purchase)> = records.into_iter().map(|r| (key(&r), r)).collect();
+
+    //  sort estável, mantém a ordem original entre chaves iguais
+    keyed.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut grouped: Vec<(K, Vec<Purchase>)> = Vec::new();
+    for (k, record) in keyed {
+        match grouped.last_mut() {
+            Some((last, group)) if *last == k => group.push(record),
+            _ => grouped.push((k, vec![record])),
+        }
+    }
+
+    return grouped.into_iter().collect();
+}
@@ -0,0 +1,57 @@
+/*
+ *  Serialize/Deserialize para BST (feature "serde").
+ *  A árvore é salva como uma sequência em ordem crescente, sem
+ *  informação de formato, e reconstruída balanceada ao carregar.
+ *  Para preservar o formato exato, veja o formato binário.
+*/
+
+use std::{cmp::Ordering, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::BST;
+
+impl<T: PartialOrd + Serialize> Serialize for BST<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.walk())
+    }
+}
+
+impl<'de, T: PartialOrd + Deserialize<'de>> Deserialize<'de> for BST<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(BstVisitor(PhantomData))
+    }
+}
+
+struct BstVisitor<T>(PhantomData<T>);
+
+impl<'de, T: PartialOrd + Deserialize<'de>> Visitor<'de> for BstVisitor<T> {
+    type Value = BST<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("uma sequência de valores")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<BST<T>, A::Error> {
+        let mut values: Vec<T> = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        //  a entrada pode ter sido escrita à mão: ordena e remove
+        //  repetidos antes de montar a árvore balanceada
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        //  com valores incomparáveis (ex: NaN) a ordem acima não é total,
+        //  e from_sorted montaria uma árvore que não é uma BST
+        if values.windows(2).any(|w| matches!(w[0].partial_cmp(&w[1]), None | Some(Ordering::Greater))) {
+            return Err(de::Error::custom("a sequência tem valores que não podem ser comparados"));
+        }
+        values.dedup_by(|a, b| a == b);
+
+        return Ok(BST::from_sorted(values));
+    }
+}
@@ -0,0 +1,88 @@
+/*
+ *  BST compartilhada entre threads, pensada para muitas leituras
+ *  e poucas escritas.
+ *  A árvore atual fica em um Arc: leitores pegam um snapshot
+ *  (uma cópia do Arc, sem copiar a árvore) e o percorrem sem
+ *  segurar nenhum lock. Escritores usam copy-on-write: se algum
+ *  snapshot ainda estiver vivo, a árvore é clonada antes de ser
+ *  alterada, então snapshots antigos nunca mudam.
+*/
+
+use std::sync::{Arc, RwLock};
+
+use crate::BST;
+
+pub struct SharedBst<T: PartialOrd> {
+    current: RwLock<Arc<BST<T>>>,
+}
+
+impl<T: PartialOrd> Default for SharedBst<T> {
+    fn default() -> Self {
+        SharedBst::new()
+    }
+}
+
+impl<T: PartialOrd> From<BST<T>> for SharedBst<T> {
+    fn from(bst: BST<T>) -> Self {
+        SharedBst { current: RwLock::new(Arc::new(bst)) }
+    }
+}
+
+impl<T: PartialOrd> SharedBst<T> {
+    /// Retorna uma nova SharedBst vazia
+    pub fn new() -> SharedBst<T> {
+        SharedBst::from(BST::new())
+    }
+
+    /// Versão imutável da árvore neste momento <br>
+    /// Escritas posteriores não aparecem no snapshot, e o snapshot
+    /// não impede que elas aconteçam
+    pub fn snapshot(&self) -> Arc<BST<T>> {
+        //  o lock é segurado apenas para copiar o Arc
+        Arc::clone(&self.current.read().expect("lock da SharedBst envenenado"))
+    }
+
+    pub fn search(&self, value: &T) -> bool {
+        self.snapshot().search(value)
+    }
+
+    pub fn total_len(&self) -> usize {
+        self.snapshot().total_len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.snapshot().is_empty()
+    }
+
+    pub fn clear(&self) {
+        //  snapshots vivos continuam com a árvore antiga
+        *self.current.write().expect("lock da SharedBst envenenado") = Arc::new(BST::new());
+    }
+}
+
+//  Escritas precisam de T: Clone para o copy-on-write
+impl<T: PartialOrd + Clone> SharedBst<T> {
+    /// Aplica f à árvore com acesso exclusivo <br>
+    /// A árvore só é clonada se houver snapshots vivos
+    pub fn write<R>(&self, f: impl FnOnce(&mut BST<T>) -> R) -> R {
+        let mut current = self.current.write().expect("lock da SharedBst envenenado");
+        return f(Arc::make_mut(&mut current));
+    }
+
+    ///  Adiciona um elemento à BST
+    ///  **false** se o elemento já existia
+    ///  **true** se o elemento foi adicionado <br>
+    pub fn insert(&self, value: T) -> bool {
+        //  evita o clone quando não há nada para inserir
+        if self.search(&value) {return false;}
+        self.write(|bst| bst.insert(value))
+    }
+
+    /// Remove um elemento da BST <Br>
+    /// **false** elemento não encontrado <br>
+    /// **true** elemento encontrado e deletado
+    pub fn delete(&self, value: &T) -> bool {
+        if !self.search(value) {return false;}
+        self.write(|bst| bst.delete(value))
+    }
+}
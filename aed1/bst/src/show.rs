@@ -0,0 +1,60 @@
+/*
+ *  Print da árvore no formato de gerenciador de arquivos, usado pelo
+ *  show_tree() da BST (nos dois backends) e da PersistentBst.
+ *  Cada implementação só informa como ir de um node para o seu valor
+ *  e os seus filhos.
+*/
+
+use std::fmt::Display;
+
+/// Imprime a árvore a partir de root <br>
+/// node(n) devolve o valor de n e os seus filhos esquerdo e direito
+pub(crate) fn show_tree<'a, N, T, F>(root: Option<N>, node: F)
+where
+    N: Copy,
+    T: Display + 'a,
+    F: Fn(N) -> (&'a T, Option<N>, Option<N>),
+{
+    if let Some(root) = root {
+        print_recursive(root, &node, 0, true);
+    } else {
+        println!("(Árvore Vazia)");
+    }
+}
+
+// Função auxiliar recursiva que faz o trabalho pesado
+fn print_recursive<'a, N, T, F>(current: N, node: &F, depth: usize, eh_o_ultimo: bool)
+where
+    N: Copy,
+    T: Display + 'a,
+    F: Fn(N) -> (&'a T, Option<N>, Option<N>),
+{
+    let (value, left, right) = node(current);
+
+    // 1. Imprimir o nó atual
+    // Cria a string de indentação e o prefixo (branch)
+    let indent = "│   ".repeat(depth);
+    let branch = if eh_o_ultimo { "└── " } else { "├── " };
+
+    println!("{}{}{}", indent, branch, value);
+
+    // O novo prefixo para os filhos é a indentação + o espaço vertical
+    let new_indent = indent + if eh_o_ultimo { "    " } else { "│   " };
+
+    // Determinar se o filho esquerdo será o "último" (se o direito for None)
+    let left_is_last = right.is_none();
+
+    // 2. Chamada Recursiva para o filho ESQUERDO
+    if let Some(left) = left {
+        print_recursive(left, node, depth + 1, left_is_last);
+    } else if right.is_some() {
+        // Desenha um nó 'vazio' se o filho direito existir, para manter o alinhamento
+        println!("{}{}└── (Vazio)", new_indent, if left_is_last { "    " } else { "│   " });
+    }
+
+    // 3. Chamada Recursiva para o filho DIREITO
+    if let Some(right) = right {
+        // O filho direito SEMPRE é o último elemento a ser desenhado naquele nível
+        print_recursive(right, node, depth + 1, true);
+    }
+}
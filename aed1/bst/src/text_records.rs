@@ -0,0 +1,167 @@
+/*
+ *  Registros de compras em texto, no formato de ORI/atv1 (compra.txt):
+ *  uma compra por linha, com nome, quantidade e preço separados por espaços.
+ *      manteiga 1 4.5
+ *      pao 1 7.99
+ *  Linhas em branco são ignoradas, e \r\n também é aceito.
+ *
+ *  Assim como no ex1.c, o nome não pode conter espaços.
+*/
+
+use std::{
+    error::Error,
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{map::BstMap, records::Purchase};
+
+/// Erros ao ler compras em texto <br>
+/// line começa em 1, como nos editores
+#[derive(Debug)]
+pub enum TextError {
+    Io(io::Error),
+    /// A linha não tem todos os campos "nome qtd preço"
+    MissingField { line: usize, field: &'static str },
+    /// A linha tem mais de 3 campos
+    ExtraField { line: usize, value: String },
+    /// Um campo não pôde ser interpretado
+    InvalidField { line: usize, field: &'static str, value: String },
+    /// A soma das quantidades de um item passou de u32::MAX
+    QtyOverflow { line: usize, name: String },
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TextError::Io(e) => write!(f, "erro de E/S: {}", e),
+            TextError::MissingField { line, field } => write!(f, "linha {}: falta o campo {}", line, field),
+            TextError::ExtraField { line, value } => write!(f, "linha {}: campo a mais: {:?}", line, value),
+            TextError::InvalidField { line, field, value } => {
+                write!(f, "linha {}: {} inválido: {:?}", line, field, value)
+            }
+            TextError::QtyOverflow { line, name } => {
+                write!(f, "linha {}: quantidade total de {:?} maior que {}", line, name, u32::MAX)
+            }
+        }
+    }
+}
+
+impl Error for TextError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TextError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for TextError {
+    fn from(e: io::Error) -> Self {
+        TextError::Io(e)
+    }
+}
+
+/// Interpreta uma linha "nome qtd preço" <br>
+/// **None** se a linha está em branco
+pub fn parse_line(text: &str, line: usize) -> Result<Option<Purchase>, TextError> {
+    let mut fields = text.split_whitespace();
+
+    let Some(name) = fields.next() else {return Ok(None);};
+    let qty = fields.next().ok_or(TextError::MissingField { line, field: "qty" })?;
+    let price = fields.next().ok_or(TextError::MissingField { line, field: "price" })?;
+
+    let invalid = |field, value: &str| TextError::InvalidField { line, field, value: value.to_string() };
+
+    let parsed_qty = qty.parse().map_err(|_| invalid("qty", qty))?;
+    let parsed_price = match price.parse::<f64>() {
+        Ok(p) if p.is_finite() && p >= 0.0 => p,
+        _ => return Err(invalid("price", price)),
+    };
+    if let Some(extra) = fields.next() {
+        return Err(TextError::ExtraField { line, value: extra.to_string() });
+    }
+
+    return Ok(Some(Purchase::new(name, parsed_qty, parsed_price)));
+}
+
+/// Lê todas as compras, parando no primeiro erro
+pub fn read_purchases<R: BufRead>(reader: R) -> Result<Vec<Purchase>, TextError> {
+    let mut purchases = Vec::new();
+
+    for (i, text) in reader.lines().enumerate() {
+        if let Some(purchase) = parse_line(&text?, i + 1)? {
+            purchases.push(purchase);
+        }
+    }
+
+    return Ok(purchases);
+}
+
+/// Junta as compras pelo nome, somando as quantidades <br>
+/// Se o mesmo item aparece com preços diferentes, o preço passa a ser
+/// a média ponderada pela quantidade, então total() continua sendo a
+/// soma do que foi gasto com ele <br>
+/// **Err** QtyOverflow se a quantidade de um item passar de u32::MAX,
+/// com line sendo a posição da compra em purchases (começando em 1)
+pub fn aggregate(purchases: impl IntoIterator<Item = Purchase>) -> Result<BstMap<String, Purchase>, TextError> {
+    let mut map: BstMap<String, Purchase> = BstMap::new();
+
+    for (i, purchase) in purchases.into_iter().enumerate() {
+        merge(&mut map, purchase, i + 1)?;
+    }
+
+    return Ok(map);
+}
+
+/// Lê as compras e as junta pelo nome (veja aggregate()) <br>
+/// Aqui line, em QtyOverflow, é a linha do texto
+pub fn load_text<R: BufRead>(reader: R) -> Result<BstMap<String, Purchase>, TextError> {
+    let mut map: BstMap<String, Purchase> = BstMap::new();
+
+    for (i, text) in reader.lines().enumerate() {
+        if let Some(purchase) = parse_line(&text?, i + 1)? {
+            merge(&mut map, purchase, i + 1)?;
+        }
+    }
+
+    return Ok(map);
+}
+
+//  Soma purchase ao item de mesmo nome, ou o adiciona ao map
+fn merge(map: &mut BstMap<String, Purchase>, purchase: Purchase, line: usize) -> Result<(), TextError> {
+    let Some(item) = map.get_mut(&purchase.name) else {
+        map.insert(purchase.name.clone(), purchase);
+        return Ok(());
+    };
+
+    let Some(qty) = item.qty.checked_add(purchase.qty) else {
+        return Err(TextError::QtyOverflow { line, name: purchase.name });
+    };
+
+    let spent = item.total() + purchase.total();
+    item.qty = qty;
+    if item.qty > 0 {
+        item.price = spent / item.qty as f64;
+    }
+    return Ok(());
+}
+
+/// Itens em ordem alfabética
+pub fn by_name(items: &BstMap<String, Purchase>) -> Vec<&Purchase> {
+    items.values().collect()
+}
+
+/// Itens do maior para o menor gasto (total()), empatados em ordem alfabética
+pub fn by_spend(items: &BstMap<String, Purchase>) -> Vec<&Purchase> {
+    let mut sorted = by_name(items);
+
+    //  sort estável: entre gastos iguais fica a ordem alfabética
+    sorted.sort_by(|a, b| b.total().total_cmp(&a.total()));
+    return sorted;
+}
+
+/// Gasto total, como o "preço total" impresso pelo ex1.c
+pub fn total(items: &BstMap<String, Purchase>) -> f64 {
+    items.values().map(Purchase::total).sum()
+}
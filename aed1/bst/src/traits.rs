@@ -0,0 +1,56 @@
+/*
+ *  Traits da std para a BST.
+ *  Implementados apenas com a interface pública (walk e cursores),
+ *  então valem para os dois backends.
+*/
+
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+};
+
+use crate::BST;
+
+//  Cópia estrutural: os valores são inseridos em pré-ordem
+//  (cada parent antes dos seus filhos), o que reproduz exatamente
+//  o formato da árvore original, e não apenas o conteúdo.
+impl<T: PartialOrd + Clone> Clone for BST<T> {
+    fn clone(&self) -> Self {
+        let mut clone = BST::new();
+        let mut pending = vec![self.cursor_root()];
+
+        while let Some(cursor) = pending.pop() {
+            let Some(value) = cursor.value() else {continue;};
+            clone.insert(value.clone());
+
+            pending.extend(cursor.right());
+            pending.extend(cursor.left());
+        }
+
+        return clone;
+    }
+}
+
+//  Duas BSTs são iguais quando guardam os mesmos valores,
+//  mesmo que tenham formatos diferentes
+impl<T: PartialOrd> PartialEq for BST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.walk() == other.walk()
+    }
+}
+
+impl<T: PartialOrd + Eq> Eq for BST<T> {}
+
+//  Consistente com PartialEq: depende apenas dos valores em ordem
+impl<T: PartialOrd + Hash> Hash for BST<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.walk().hash(state);
+    }
+}
+
+//  Formato de conjunto, em ordem crescente: {1, 2, 3}
+impl<T: PartialOrd + Debug> Debug for BST<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.walk()).finish()
+    }
+}
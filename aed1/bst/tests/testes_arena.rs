@@ -0,0 +1,26 @@
+//  Testes específicos do backend com arena
+//      cargo test --test testes_arena --features arena
+
+#[cfg(all(test, feature = "arena"))]
+mod tests {
+    use bst::BST;
+
+    fn from_values(values: &[i32]) -> BST<i32> {
+        let mut bst = BST::new();
+        for &value in values {
+            bst.insert(value);
+        }
+        bst
+    }
+
+    #[test]
+    fn is_balanced_checks_both_sides() {
+        assert!(!from_values(&[1, 2, 3]).is_balanced());
+        assert!(!from_values(&[3, 2, 1]).is_balanced());
+        assert!(!from_values(&[2, 1, 3, 4, 5]).is_balanced());
+
+        assert!(from_values(&[2, 1, 3, 4]).is_balanced());
+        assert!(from_values(&[3, 2, 4, 1]).is_balanced());
+        assert!(BST::<i32>::new().is_balanced());
+    }
+}
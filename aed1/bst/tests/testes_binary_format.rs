@@ -0,0 +1,203 @@
+#[cfg(test)]
+mod tests {
+    use bst::{
+        binary_format::{self, BinaryValue, MAGIC},
+        Cursor, FormatError, BST,
+    };
+
+    fn from_values<T: PartialOrd + Clone>(values: &[T]) -> BST<T> {
+        let mut bst = BST::new();
+        for value in values {
+            bst.insert(value.clone());
+        }
+        bst
+    }
+
+    /// Valores em pré-ordem com a profundidade, para comparar formatos
+    fn shape<T: PartialOrd + Clone>(bst: &BST<T>) -> Vec<(T, usize)> {
+        fn visit<T: PartialOrd + Clone>(cursor: Option<Cursor<'_, T>>, out: &mut Vec<(T, usize)>) {
+            let Some(cursor) = cursor else {return;};
+            out.push((cursor.value().unwrap().clone(), cursor.depth().unwrap()));
+            visit(cursor.left(), out);
+            visit(cursor.right(), out);
+        }
+
+        let mut out = Vec::new();
+        if !bst.is_empty() {
+            visit(Some(bst.cursor_root()), &mut out);
+        }
+        out
+    }
+
+    //  Substitui o checksum, simulando uma corrupção que não é detectada por ele
+    fn fix_checksum(bytes: &mut [u8]) {
+        let len = bytes.len();
+        let sum = binary_format::checksum(&bytes[..len - 4]);
+        bytes[len - 4..].copy_from_slice(&sum.to_le_bytes());
+    }
+
+    #[test]
+    fn round_trip_preserves_shape() {
+        let bst = from_values(&[8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let loaded: BST<i32> = BST::from_bytes(&bst.to_bytes().unwrap()).unwrap();
+
+        assert_eq!(shape(&loaded), shape(&bst));
+        assert_eq!(loaded.altura(), bst.altura());
+        assert_eq!(loaded.leafs_len(), bst.leafs_len());
+    }
+
+    #[test]
+    fn round_trip_is_byte_identical() {
+        let bst = from_values(&[1, 2, 3, 4, 5]);
+        let bytes = bst.to_bytes().unwrap();
+        let loaded: BST<i32> = BST::from_bytes(&bytes).unwrap();
+
+        assert_eq!(loaded.altura(), 5);
+        assert_eq!(loaded.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn round_trip_after_deletions() {
+        let mut bst = from_values(&[50, 30, 70, 20, 40, 60, 80, 35, 45]);
+        bst.delete(&30);
+        bst.delete(&70);
+
+        let loaded: BST<i32> = BST::from_bytes(&bst.to_bytes().unwrap()).unwrap();
+        assert_eq!(shape(&loaded), shape(&bst));
+    }
+
+    #[test]
+    fn round_trip_strings() {
+        let names = ["manteiga", "pao", "arroz", "feijao", "carne", "detergente", "sabão"];
+        let bst = from_values(&names.map(String::from));
+
+        let loaded: BST<String> = BST::from_bytes(&bst.to_bytes().unwrap()).unwrap();
+        assert_eq!(shape(&loaded), shape(&bst));
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let bst: BST<u64> = BST::new();
+        let bytes = bst.to_bytes().unwrap();
+
+        assert_eq!(&bytes[..4], &MAGIC);
+        assert!(BST::<u64>::from_bytes(&bytes).unwrap().is_empty());
+    }
+
+    #[test]
+    fn write_to_and_read_from() {
+        let bst = from_values(&[2, 1, 3]);
+        let mut file = Vec::new();
+        bst.write_to(&mut file).unwrap();
+
+        let loaded: BST<i32> = BST::read_from(file.as_slice()).unwrap();
+        assert_eq!(shape(&loaded), shape(&bst));
+    }
+
+    #[test]
+    fn every_truncation_is_an_error() {
+        let bytes = from_values(&[2, 1, 3]).to_bytes().unwrap();
+
+        for len in 0..bytes.len() {
+            let result = BST::<i32>::from_bytes(&bytes[..len]);
+            assert!(matches!(result, Err(FormatError::Truncated)), "tamanho {}", len);
+        }
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut bytes = from_values(&[1]).to_bytes().unwrap();
+        bytes[0] = b'X';
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::BadMagic)));
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut bytes = from_values(&[1]).to_bytes().unwrap();
+        bytes[4] = 99;
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::UnsupportedVersion(99))));
+    }
+
+    #[test]
+    fn corrupted_byte_fails_checksum() {
+        let mut bytes = from_values(&[2, 1, 3]).to_bytes().unwrap();
+        let len = bytes.len();
+        bytes[len - 6] ^= 0xff;
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn trailing_bytes() {
+        let mut bytes = from_values(&[2, 1, 3]).to_bytes().unwrap();
+        bytes.push(0);
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::TrailingBytes)));
+    }
+
+    #[test]
+    fn out_of_order_values_are_not_a_bst() {
+        //  root 2, esquerda 1, direita 3: troca os valores das folhas
+        let mut bytes = from_values(&[2, 1, 3]).to_bytes().unwrap();
+        let one = bytes.windows(4).position(|w| w == 1i32.to_le_bytes()).unwrap();
+        let three = bytes.windows(4).position(|w| w == 3i32.to_le_bytes()).unwrap();
+        bytes[one..one + 4].copy_from_slice(&3i32.to_le_bytes());
+        bytes[three..three + 4].copy_from_slice(&1i32.to_le_bytes());
+        fix_checksum(&mut bytes);
+
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::NotABst)));
+    }
+
+    #[test]
+    fn invalid_marker() {
+        let mut bytes = from_values(&[1]).to_bytes().unwrap();
+        bytes[21] = 7;
+        fix_checksum(&mut bytes);
+        assert!(matches!(BST::<i32>::from_bytes(&bytes), Err(FormatError::InvalidMarker(7))));
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut bytes = from_values(&["a".to_string()]).to_bytes().unwrap();
+        //  marcador, tamanho (4 bytes) e então o conteúdo da string
+        bytes[21 + 1 + 4] = 0xff;
+        fix_checksum(&mut bytes);
+        assert!(matches!(BST::<String>::from_bytes(&bytes), Err(FormatError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn binary_value_round_trip() {
+        let mut out = Vec::new();
+        (-5i16).encode(&mut out).unwrap();
+        2.5f64.encode(&mut out).unwrap();
+        "olá".to_string().encode(&mut out).unwrap();
+
+        let mut input = out.as_slice();
+        assert_eq!(i16::decode(&mut input).unwrap(), -5);
+        assert_eq!(f64::decode(&mut input).unwrap(), 2.5);
+        assert_eq!(String::decode(&mut input).unwrap(), "olá");
+        assert!(input.is_empty());
+    }
+
+    //  valor que não cabe no formato, como uma String com mais de u32::MAX bytes
+    #[derive(PartialEq, PartialOrd)]
+    struct Grande(u32);
+
+    impl BinaryValue for Grande {
+        fn encode(&self, _out: &mut Vec<u8>) -> Result<(), FormatError> {
+            Err(FormatError::TooLarge(self.0 as usize))
+        }
+
+        fn decode(input: &mut &[u8]) -> Result<Self, FormatError> {
+            u32::decode(input).map(Grande)
+        }
+    }
+
+    #[test]
+    fn encode_error_is_returned() {
+        let mut bst = BST::new();
+        bst.insert(Grande(7));
+
+        assert!(matches!(bst.to_bytes(), Err(FormatError::TooLarge(7))));
+        assert!(matches!(bst.write_to(Vec::new()), Err(FormatError::TooLarge(7))));
+        assert!(BST::<Grande>::new().to_bytes().is_ok());
+    }
+}
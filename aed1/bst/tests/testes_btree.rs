@@ -0,0 +1,238 @@
+//  Testes da BTree. O teste baseado em modelo compara com um
+//  BTreeMap da std depois de cada operação, como em testes_modelo.rs
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use bst::{
+        binary_format::FormatError, BTree, BTreeError, FilePager, MemoryPager, Pager,
+    };
+
+    const SEQUENCIAS: u64 = if cfg!(miri) { 2 } else { 30 };
+    const OPERACOES: usize = if cfg!(miri) { 60 } else { 600 };
+    const VALOR_MAX: u64 = 120;
+
+    /// Gerador xorshift64, determinístico para que falhas sejam reproduzíveis
+    struct Rng(u64);
+
+    impl Rng {
+        fn new(seed: u64) -> Rng {
+            Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+    }
+
+    fn memory_tree(order: usize) -> BTree<u32, u64, MemoryPager> {
+        BTree::create(MemoryPager::new(512), order).unwrap()
+    }
+
+    fn collect<P: Pager>(tree: &BTree<u32, u64, P>) -> Vec<(u32, u64)> {
+        tree.iter().collect::<Result<_, _>>().unwrap()
+    }
+
+    fn run_sequence(seed: u64, order: usize) {
+        let mut rng = Rng::new(seed);
+        let mut tree = memory_tree(order);
+        let mut model = BTreeMap::new();
+
+        for step in 0..OPERACOES {
+            let ctx = format!("ordem {} seed {} passo {}", order, seed, step);
+            let key = rng.below(VALOR_MAX) as u32;
+
+            match rng.below(10) {
+                0..=4 => {
+                    let value = rng.next();
+                    assert_eq!(tree.insert(key, value).unwrap(), model.insert(key, value), "{}", ctx);
+                }
+                5..=7 => {
+                    assert_eq!(tree.remove(&key).unwrap(), model.remove(&key), "{}", ctx);
+                }
+                8 => {
+                    assert_eq!(tree.get(&key).unwrap(), model.get(&key).copied(), "{}", ctx);
+                }
+                _ => {
+                    let end = key + rng.below(20) as u32;
+                    let found: Vec<(u32, u64)> = tree.range(key..end).collect::<Result<_, _>>().unwrap();
+                    let expected: Vec<(u32, u64)> = model.range(key..end).map(|(k, v)| (*k, *v)).collect();
+                    assert_eq!(found, expected, "{}", ctx);
+                }
+            }
+
+            assert_eq!(tree.len(), model.len() as u64, "{}", ctx);
+            assert!(tree.is_valid().unwrap(), "{}", ctx);
+        }
+
+        assert_eq!(collect(&tree), model.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn random_operations_match_btreemap() {
+        for order in [3, 4, 5, 8] {
+            for seed in 0..SEQUENCIAS {
+                run_sequence(seed, order);
+            }
+        }
+    }
+
+    #[test]
+    fn insert_get_and_replace() {
+        let mut tree = memory_tree(4);
+
+        assert_eq!(tree.insert(10, 100).unwrap(), None);
+        assert_eq!(tree.insert(10, 101).unwrap(), Some(100));
+        assert_eq!(tree.get(&10).unwrap(), Some(101));
+        assert_eq!(tree.get(&11).unwrap(), None);
+        assert!(tree.contains_key(&10).unwrap());
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn height_grows_logarithmically() {
+        let mut tree = memory_tree(5);
+        for key in 0..1000 {
+            tree.insert(key, key as u64).unwrap();
+        }
+
+        //  com pelo menos 3 filhos por node interno, 1000 chaves cabem em 7 níveis
+        assert!(tree.height().unwrap() <= 7);
+        assert!(tree.is_valid().unwrap());
+        assert_eq!(collect(&tree).len(), 1000);
+    }
+
+    #[test]
+    fn range_bounds() {
+        use std::ops::Bound;
+
+        let mut tree = memory_tree(3);
+        for key in (0..50).step_by(5) {
+            tree.insert(key, 0).unwrap();
+        }
+        let keys = |r: Vec<(u32, u64)>| r.into_iter().map(|(k, _)| k).collect::<Vec<_>>();
+
+        assert_eq!(keys(tree.range(10..25).collect::<Result<_, _>>().unwrap()), [10, 15, 20]);
+        assert_eq!(keys(tree.range(11..=25).collect::<Result<_, _>>().unwrap()), [15, 20, 25]);
+        assert_eq!(keys(tree.range(..10).collect::<Result<_, _>>().unwrap()), [0, 5]);
+        assert_eq!(keys(tree.range(40..).collect::<Result<_, _>>().unwrap()), [40, 45]);
+        assert_eq!(keys(tree.range(46..).collect::<Result<_, _>>().unwrap()), Vec::<u32>::new());
+
+        let excluded = (Bound::Excluded(10), Bound::Excluded(25));
+        assert_eq!(keys(tree.range(excluded).collect::<Result<_, _>>().unwrap()), [15, 20]);
+    }
+
+    #[test]
+    fn removing_everything_frees_pages_for_reuse() {
+        let mut tree = memory_tree(4);
+        for key in 0..200 {
+            tree.insert(key, 0).unwrap();
+        }
+        let pages = tree.page_count();
+
+        for key in 0..200 {
+            assert_eq!(tree.remove(&key).unwrap(), Some(0));
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height().unwrap(), 0);
+
+        //  as páginas livres são reaproveitadas antes de criar novas
+        for key in 0..200 {
+            tree.insert(key, 1).unwrap();
+        }
+        assert_eq!(tree.page_count(), pages);
+        assert!(tree.is_valid().unwrap());
+    }
+
+    #[test]
+    fn reopen_from_pager() {
+        let mut tree = memory_tree(6);
+        for key in 0..100 {
+            tree.insert(key, key as u64 * 2).unwrap();
+        }
+        tree.remove(&50).unwrap();
+
+        let reopened: BTree<u32, u64, _> = BTree::open(tree.into_pager()).unwrap();
+        assert_eq!(reopened.order(), 6);
+        assert_eq!(reopened.len(), 99);
+        assert_eq!(reopened.get(&51).unwrap(), Some(102));
+        assert_eq!(reopened.get(&50).unwrap(), None);
+        assert!(reopened.is_valid().unwrap());
+    }
+
+    #[test]
+    fn file_pager_round_trip() {
+        let dir = std::env::temp_dir().join(format!("bst-btree-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("arvore.db");
+        let _ = std::fs::remove_file(&path);
+
+        let mut tree: BTree<String, f64, _> = BTree::create(FilePager::open(&path, 256).unwrap(), 4).unwrap();
+        for (name, price) in [("manteiga", 4.5), ("pao", 7.99), ("arroz", 10.95), ("feijao", 9.4), ("carne", 21.9)] {
+            tree.insert(name.to_string(), price).unwrap();
+        }
+        tree.flush().unwrap();
+        drop(tree);
+
+        assert_eq!(std::fs::metadata(&path).unwrap().len() % 256, 0);
+
+        let tree: BTree<String, f64, _> = BTree::open(FilePager::open(&path, 256).unwrap()).unwrap();
+        let names: Vec<String> = tree.iter().map(|r| r.unwrap().0).collect();
+        assert_eq!(names, ["arroz", "carne", "feijao", "manteiga", "pao"]);
+        assert_eq!(tree.get(&"pao".to_string()).unwrap(), Some(7.99));
+    }
+
+    #[test]
+    fn entry_too_large() {
+        let mut tree: BTree<String, u8, _> = BTree::create(MemoryPager::new(128), 4).unwrap();
+        let max = tree.max_entry_size();
+
+        //  a string ocupa 4 bytes de tamanho mais o conteúdo, e o valor 1 byte
+        assert!(tree.insert("a".repeat(max - 5), 0).is_ok());
+        let result = tree.insert("a".repeat(max - 4), 0);
+        assert!(matches!(result, Err(BTreeError::EntryTooLarge { .. })));
+        assert_eq!(tree.len(), 1);
+    }
+
+    #[test]
+    fn invalid_configuration() {
+        let result: Result<BTree<u32, u64, _>, _> = BTree::create(MemoryPager::new(512), 2);
+        assert!(matches!(result, Err(BTreeError::InvalidOrder(2))));
+
+        let result: Result<BTree<u32, u64, _>, _> = BTree::create(MemoryPager::new(64), 10);
+        assert!(matches!(result, Err(BTreeError::PageTooSmall { .. })));
+
+        let mut pager = MemoryPager::new(512);
+        pager.allocate().unwrap();
+        let result: Result<BTree<u32, u64, _>, _> = BTree::create(pager, 4);
+        assert!(matches!(result, Err(BTreeError::PagerNotEmpty)));
+    }
+
+    #[test]
+    fn open_rejects_bad_header() {
+        let mut pager = MemoryPager::new(512);
+        pager.allocate().unwrap();
+        let result: Result<BTree<u32, u64, _>, _> = BTree::open(pager);
+        assert!(matches!(result, Err(BTreeError::Format(FormatError::BadMagic))));
+
+        let tree = memory_tree(4);
+        let mut page = vec![0; 512];
+        let pager = tree.into_pager();
+        pager.read_page(0, &mut page).unwrap();
+
+        let mut other = MemoryPager::new(1024);
+        other.allocate().unwrap();
+        page.resize(1024, 0);
+        other.write_page(0, &page).unwrap();
+        let result: Result<BTree<u32, u64, _>, _> = BTree::open(other);
+        assert!(matches!(result, Err(BTreeError::PageSizeMismatch { expected: 512, found: 1024 })));
+    }
+}
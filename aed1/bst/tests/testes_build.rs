@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use bst::BST;
+
+    #[test]
+    fn from_sorted_is_balanced() {
+        let bst = BST::from_sorted((1..=15).collect());
+
+        assert_eq!(bst.total_len(), 15);
+        assert_eq!(bst.altura(), 4);
+        assert_eq!(bst.cursor_root().value(), Some(&8));
+        assert!(bst.is_balanced());
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn from_sorted_empty() {
+        let bst: BST<i32> = BST::from_sorted(Vec::new());
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn from_sorted_unsorted_input_is_still_a_bst() {
+        let bst = BST::from_sorted(vec![5, 1, 4, 1, 3]);
+
+        assert_eq!(bst.walk(), vec![&1, &3, &4, &5]);
+        assert!(bst.is_bst());
+    }
+}
@@ -0,0 +1,138 @@
+//  Testes do bst-cli: roda o binário com um roteiro na entrada padrão
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        path::PathBuf,
+        process::{Command, Stdio},
+    };
+
+    fn run(args: &[&str], script: &str) -> String {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_bst-cli"))
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        child.stdin.take().unwrap().write_all(script.as_bytes()).unwrap();
+        let output = child.wait_with_output().unwrap();
+        assert!(output.status.success());
+
+        //  remove os prompts, deixando só as respostas
+        String::from_utf8(output.stdout).unwrap().replace("> ", "")
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bst-cli-{}-{}", std::process::id(), name))
+    }
+
+    fn ori(path: &str) -> String {
+        format!("{}/../../ORI/{}", env!("CARGO_MANIFEST_DIR"), path)
+    }
+
+    #[test]
+    fn integer_commands() {
+        let out = run(&[], "insert 5 3 8 1 4\ninsert 3\nfind 4\nfind 7\nmin\nmax\nrange 2 5\ndelete 3\nfind 3\n");
+
+        assert_eq!(out, "5 inserido(s)\n0 inserido(s)\n4\n7 não encontrado\n1\n8\n3\n4\n5\nremovido 3\n3 não encontrado\n");
+    }
+
+    #[test]
+    fn undo_restores_previous_version() {
+        let out = run(&[], "insert 2 1 3\ndelete 2\nundo\nrange 0 9\nundo\nundo\nmin\n");
+
+        assert_eq!(out, "3 inserido(s)\nremovido 2\ndesfeito\n1\n2\n3\ndesfeito\nerro: nada para desfazer\n(Árvore Vazia)\n");
+    }
+
+    #[test]
+    fn show_and_stats() {
+        let out = run(&[], "insert 2 1 3\nshow\nstats\n");
+
+        assert!(out.contains("└── 2\n"));
+        assert!(out.contains("Altura: 2\n"));
+        assert!(out.contains("Número de folhas: 2\n"));
+    }
+
+    #[test]
+    fn save_and_load_keep_shape() {
+        let path = temp_path("ints.bst");
+        let path = path.to_str().unwrap();
+
+        let saved = run(&[], &format!("insert 1 2 3 4\nsave {}\nshow\n", path));
+        let loaded = run(&[path], "show\nstats\n");
+
+        let tree = |out: &str| out.lines().filter(|l| l.contains("──")).collect::<Vec<_>>().join("\n");
+        assert_eq!(tree(&saved), tree(&loaded));
+        assert!(loaded.contains("Altura: 4\n"));
+    }
+
+    #[test]
+    fn records_from_files() {
+        let out = run(&[&ori("atv1/compra.txt")], "find arroz\nrange c f\nstats\n");
+        assert!(out.starts_with("carregado"));
+        assert!(out.contains("arroz (2 x 10.95)\n"));
+        assert!(out.contains("carne (2 x 21.90)\ndetergente (3 x 1.65)\n"));
+        assert!(out.contains("Está balanceada? true\n"));
+
+        let out = run(&[&ori("atv2/compras.bin")], "find sabao\n");
+        assert!(out.contains("sabao (2 x 3.45)\n"));
+    }
+
+    #[test]
+    fn file_argument_with_spaces() {
+        let path = temp_path("minhas compras.txt");
+        std::fs::write(&path, "pao 1 7.99\n").unwrap();
+
+        let out = run(&[path.to_str().unwrap()], "find pao\n");
+        assert!(out.starts_with("carregado"));
+        assert!(out.contains("pao (1 x 7.99)\n"));
+    }
+
+    #[test]
+    fn invalid_input_is_an_error() {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_bst-cli"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+
+        child.stdin.take().unwrap().write_all(b"insert 1\nfind \xff\nmin\n").unwrap();
+        let output = child.wait_with_output().unwrap();
+
+        assert!(!output.status.success());
+        assert!(String::from_utf8_lossy(&output.stderr).starts_with("erro: "));
+        assert_eq!(String::from_utf8(output.stdout).unwrap().replace("> ", ""), "1 inserido(s)\n");
+    }
+
+    #[test]
+    fn records_save_as_text() {
+        let path = temp_path("compras.txt");
+        let path = path.to_str().unwrap();
+
+        run(&[], &format!("mode compras\ninsert pao 1 7.99 leite 2 5.49\nsave {}\n", path));
+        assert_eq!(std::fs::read_to_string(path).unwrap(), "leite 2 5.49\npao 1 7.99\n");
+
+        let out = run(&[path], "delete pao\nmax\n");
+        assert!(out.contains("leite (2 x 5.49)\n"));
+    }
+
+    #[test]
+    fn errors_do_not_stop_the_session() {
+        let out = run(&[], "insert x\nfoo\nload /nao/existe\ninsert 1\nquit\ninsert 2\n");
+
+        assert!(out.contains("erro: inteiro inválido: \"x\"\n"));
+        assert!(out.contains("erro: comando inválido: foo (veja help)\n"));
+        assert!(out.contains("erro: /nao/existe"));
+        assert!(out.ends_with("1 inserido(s)\n"));
+    }
+
+    #[test]
+    fn record_errors_have_the_input_line() {
+        let out = run(&[], "mode compras\ninsert pao 1 7.99\n\ninsert leite x 5.49\n");
+
+        assert!(out.contains("erro: linha 4: qty inválido: \"x\"\n"));
+    }
+}
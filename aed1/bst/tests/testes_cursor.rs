@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use bst::BST;
+
+    /// Raiz: 3. Filhos: 1, 5. Netos: 0, 2, 4, 6.
+    fn setup_complete_tree() -> BST<i32> {
+        let mut bst = BST::new();
+        for val in [3, 1, 5, 0, 2, 4, 6] {
+            bst.insert(val);
+        }
+        bst
+    }
+
+    #[test]
+    fn cursor_empty_tree() {
+        let bst: BST<i32> = BST::new();
+        let mut cursor = bst.cursor_root();
+
+        assert_eq!(cursor.value(), None);
+        assert_eq!(cursor.depth(), None);
+        assert!(cursor.left().is_none());
+
+        cursor.move_next();
+        assert_eq!(cursor.value(), None);
+    }
+
+    #[test]
+    fn cursor_navigates_structure() {
+        let bst = setup_complete_tree();
+        let root = bst.cursor_root();
+
+        assert_eq!(root.value(), Some(&3));
+        assert_eq!(root.depth(), Some(0));
+        assert!(root.parent().is_none());
+
+        let left = root.left().unwrap();
+        assert_eq!(left.value(), Some(&1));
+        assert_eq!(left.right().unwrap().value(), Some(&2));
+        assert_eq!(left.right().unwrap().depth(), Some(2));
+        assert_eq!(left.parent().unwrap().value(), Some(&3));
+        assert_eq!(root.right().unwrap().left().unwrap().value(), Some(&4));
+        assert!(left.left().unwrap().left().is_none());
+    }
+
+    #[test]
+    fn cursor_move_next_walks_in_order() {
+        let bst = setup_complete_tree();
+        let mut cursor = bst.cursor_front();
+        let mut values = Vec::new();
+
+        while let Some(value) = cursor.value() {
+            values.push(value);
+            cursor.move_next();
+        }
+
+        assert_eq!(values, bst.walk());
+
+        //  fora da árvore, avançar volta para o menor elemento
+        cursor.move_next();
+        assert_eq!(cursor.value(), Some(&0));
+    }
+
+    #[test]
+    fn cursor_move_prev_walks_in_reverse() {
+        let bst = setup_complete_tree();
+        let mut cursor = bst.cursor_back();
+        let mut values = Vec::new();
+
+        while let Some(value) = cursor.value() {
+            values.push(value);
+            cursor.move_prev();
+        }
+
+        assert_eq!(values, bst.walk_reverse());
+    }
+
+    #[test]
+    fn cursor_move_next_skewed_tree() {
+        let mut bst = BST::new();
+        for i in (1..=5).rev() {
+            bst.insert(i);
+        }
+
+        let mut cursor = bst.cursor_at(&3);
+        cursor.move_next();
+        assert_eq!(cursor.value(), Some(&4));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.value(), None);
+    }
+
+    #[test]
+    fn cursor_at_missing_value() {
+        let bst = setup_complete_tree();
+        assert_eq!(bst.cursor_at(&10).value(), None);
+        assert_eq!(bst.cursor_at(&4).depth(), Some(2));
+    }
+
+    #[test]
+    fn cursor_mut_moves_through_children() {
+        let mut bst = setup_complete_tree();
+        let mut cursor = bst.cursor_root_mut();
+
+        assert!(cursor.move_right());
+        assert!(cursor.move_left());
+        assert_eq!(cursor.value(), Some(&4));
+        assert!(!cursor.move_left());
+        assert_eq!(cursor.value(), Some(&4));
+        assert!(cursor.move_parent());
+        assert_eq!(cursor.as_cursor().left().unwrap().value(), Some(&4));
+    }
+
+    #[test]
+    fn cursor_mut_remove_current_moves_to_sucessor() {
+        let mut bst = setup_complete_tree();
+        let mut cursor = bst.cursor_root_mut();
+
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.value(), Some(&4));
+
+        assert_eq!(bst.walk(), vec![&0, &1, &2, &4, &5, &6]);
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn cursor_mut_remove_all_from_front() {
+        let mut bst = setup_complete_tree();
+        let mut cursor = bst.cursor_front_mut();
+        let mut removed = Vec::new();
+
+        while let Some(value) = cursor.remove_current() {
+            removed.push(value);
+        }
+
+        assert_eq!(removed, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn cursor_mut_remove_even_values() {
+        let mut bst = setup_complete_tree();
+        let mut cursor = bst.cursor_front_mut();
+
+        while let Some(&value) = cursor.value() {
+            if value % 2 == 0 {
+                cursor.remove_current();
+            } else {
+                cursor.move_next();
+            }
+        }
+
+        assert_eq!(bst.walk(), vec![&1, &3, &5]);
+        assert!(bst.is_bst());
+    }
+}
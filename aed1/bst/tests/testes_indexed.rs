@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use bst::{
+        indexed::IndexError,
+        records::{self, Purchase},
+        IndexedRecordFile,
+    };
+
+    /// Diretório temporário próprio de cada teste, com uma cópia do compras.bin
+    fn setup(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("bst-indexed-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let original = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../ORI/atv2/compras.bin");
+        fs::copy(original, dir.join("compras.bin")).unwrap();
+        dir
+    }
+
+    fn by_name(record: &Purchase) -> String {
+        record.name.clone()
+    }
+
+    fn names(records: Vec<Purchase>) -> Vec<String> {
+        records.into_iter().map(|r| r.name).collect()
+    }
+
+    #[test]
+    fn get_by_key() {
+        let dir = setup("get");
+        let file = IndexedRecordFile::open(dir.join("compras.bin"), by_name).unwrap();
+
+        assert_eq!(file.len(), 7);
+        assert_eq!(file.get(&"carne".to_string()).unwrap(), Some(Purchase::new("carne", 2, 21.9)));
+        assert_eq!(file.get(&"leite".to_string()).unwrap(), None);
+    }
+
+    #[test]
+    fn range_in_key_order() {
+        let dir = setup("range");
+        let file = IndexedRecordFile::open(dir.join("compras.bin"), by_name).unwrap();
+
+        let all: Vec<Purchase> = file.range(..).collect::<Result<_, _>>().unwrap();
+        assert_eq!(names(all), ["arroz", "carne", "detergente", "feijao", "manteiga", "pao", "sabao"]);
+
+        let some: Vec<Purchase> = file.range("c".to_string().."g".to_string()).collect::<Result<_, _>>().unwrap();
+        assert_eq!(names(some), ["carne", "detergente", "feijao"]);
+    }
+
+    #[test]
+    fn repeated_keys_keep_file_order() {
+        let dir = setup("repetidas");
+        let file = IndexedRecordFile::open(dir.join("compras.bin"), |r| r.qty).unwrap();
+
+        assert_eq!(names(file.get_all(&2).unwrap()), ["arroz", "carne", "sabao"]);
+        assert_eq!(file.get(&3).unwrap().unwrap().name, "feijao");
+
+        let offsets: Vec<u64> = file.offsets(1..=1).map(|(_, offset)| offset).collect();
+        assert_eq!(offsets, [0, 16]);
+
+        let above: Vec<Purchase> = file.range(2..).collect::<Result<_, _>>().unwrap();
+        assert_eq!(names(above), ["arroz", "carne", "sabao", "feijao", "detergente"]);
+    }
+
+    #[test]
+    fn append_updates_index_and_file() {
+        let dir = setup("append");
+        let path = dir.join("compras.bin");
+        let mut file = IndexedRecordFile::open(&path, by_name).unwrap();
+
+        let leite = Purchase::new("leite", 6, 5.49);
+        let offset = file.append(&leite).unwrap();
+        file.flush().unwrap();
+
+        assert_eq!(offset, 100);
+        assert_eq!(file.len(), 8);
+        assert_eq!(file.get(&"leite".to_string()).unwrap(), Some(leite.clone()));
+
+        //  o registro foi de fato escrito no fim do arquivo de dados
+        let records = records::read_records(fs::File::open(&path).unwrap()).unwrap();
+        assert_eq!(records.last(), Some(&leite));
+        assert_eq!(records.len(), 8);
+    }
+
+    #[test]
+    fn append_too_long_writes_nothing() {
+        let dir = setup("append_longo");
+        let path = dir.join("compras.bin");
+        let mut file = IndexedRecordFile::open(&path, by_name).unwrap();
+
+        let result = file.append(&Purchase::new(&"a".repeat(300), 1, 1.0));
+        assert!(matches!(result, Err(IndexError::Record(_))));
+        assert_eq!(fs::metadata(&path).unwrap().len(), 100);
+        assert_eq!(file.len(), 7);
+    }
+
+    #[test]
+    fn save_and_load_index() {
+        let dir = setup("salva");
+        let path = dir.join("compras.bin");
+        let mut file = IndexedRecordFile::open(&path, by_name).unwrap();
+        file.append(&Purchase::new("leite", 6, 5.49)).unwrap();
+        file.save_index(dir.join("compras.idx")).unwrap();
+        drop(file);
+
+        let loaded = IndexedRecordFile::open_with_index(&path, dir.join("compras.idx"), by_name).unwrap();
+        assert_eq!(loaded.len(), 8);
+        assert_eq!(loaded.get(&"leite".to_string()).unwrap().unwrap().qty, 6);
+        assert_eq!(loaded.get(&"arroz".to_string()).unwrap().unwrap().price, 10.95);
+    }
+
+    #[test]
+    fn stale_index_is_rejected() {
+        let dir = setup("desatualizado");
+        let path = dir.join("compras.bin");
+        IndexedRecordFile::open(&path, by_name).unwrap().save_index(dir.join("compras.idx")).unwrap();
+
+        //  outro programa acrescenta um registro sem atualizar o índice
+        let mut other = IndexedRecordFile::open(&path, by_name).unwrap();
+        other.append(&Purchase::new("leite", 6, 5.49)).unwrap();
+
+        let result = IndexedRecordFile::open_with_index(&path, dir.join("compras.idx"), by_name);
+        assert!(matches!(result, Err(IndexError::StaleIndex { indexed: 100, actual: 113 })));
+    }
+
+    #[test]
+    fn corrupted_index_is_rejected() {
+        let dir = setup("corrompido");
+        let path = dir.join("compras.bin");
+        IndexedRecordFile::open(&path, by_name).unwrap().save_index(dir.join("compras.idx")).unwrap();
+
+        let mut bytes = fs::read(dir.join("compras.idx")).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        fs::write(dir.join("compras.idx"), bytes).unwrap();
+
+        let result = IndexedRecordFile::open_with_index(&path, dir.join("compras.idx"), by_name);
+        assert!(matches!(result, Err(IndexError::Format(_))));
+    }
+
+    #[test]
+    fn new_file_starts_empty() {
+        let dir = setup("vazio");
+        let mut file = IndexedRecordFile::open(dir.join("novo.bin"), |r| r.qty).unwrap();
+        assert!(file.is_empty());
+
+        assert_eq!(file.append(&Purchase::new("pao", 1, 7.99)).unwrap(), 0);
+        assert_eq!(file.append(&Purchase::new("ovo", 12, 0.8)).unwrap(), 11);
+        assert_eq!(names(file.range(..).collect::<Result<_, _>>().unwrap()), ["pao", "ovo"]);
+    }
+}
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use bst::{BstKey, BST};
+
+    //  Implementação manual, equivalente ao que o derive gera
+    #[derive(Debug)]
+    struct Registro {
+        id: i32,
+        nome: String,
+    }
+
+    impl BstKey for Registro {
+        type Key = i32;
+
+        fn key(&self) -> &i32 {
+            &self.id
+        }
+    }
+
+    impl PartialEq for Registro {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id
+        }
+    }
+
+    impl PartialOrd for Registro {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            self.id.partial_cmp(&other.id)
+        }
+    }
+
+    fn setup_tree() -> BST<Registro> {
+        let mut bst = BST::new();
+        for (id, nome) in [(16, "Alex"), (8, "Ana"), (24, "Bruno"), (4, "Maria"), (12, "Lucas")] {
+            bst.insert(Registro { id, nome: nome.to_string() });
+        }
+        bst
+    }
+
+    #[test]
+    fn get_by_key() {
+        let bst = setup_tree();
+
+        assert_eq!(bst.get_by_key(&12).unwrap().nome, "Lucas");
+        assert!(bst.get_by_key(&13).is_none());
+        assert!(bst.contains_key(&4));
+        assert!(!bst.contains_key(&5));
+    }
+
+    #[test]
+    fn get_by_key_mut() {
+        let mut bst = setup_tree();
+        bst.get_by_key_mut(&24).unwrap().nome.push_str(" Silva");
+
+        assert_eq!(bst.get_by_key(&24).unwrap().nome, "Bruno Silva");
+    }
+
+    #[test]
+    fn remove_by_key() {
+        let mut bst = setup_tree();
+
+        let removed = bst.remove_by_key(&16).unwrap();
+        assert_eq!(removed.nome, "Alex");
+        assert!(bst.remove_by_key(&16).is_none());
+        assert_eq!(bst.total_len(), 4);
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn cursor_at_key() {
+        let bst = setup_tree();
+
+        let mut cursor = bst.cursor_at_key(&8);
+        assert_eq!(cursor.value().unwrap().nome, "Ana");
+        cursor.move_next();
+        assert_eq!(cursor.value().unwrap().id, 12);
+
+        assert!(bst.cursor_at_key(&10).value().is_none());
+        assert!(bst.cursor_at_key(&100).value().is_none());
+    }
+
+    #[cfg(feature = "derive")]
+    mod derive {
+        use bst::{BstKey, BST};
+
+        #[derive(BstKey, Debug)]
+        struct Registro {
+            #[key]
+            id: u32,
+            nome: String,
+        }
+
+        #[derive(BstKey)]
+        struct Par<T>(T, #[key] String);
+
+        #[test]
+        fn derived_comparisons_use_only_the_key() {
+            let a = Registro { id: 1, nome: "a".to_string() };
+            let b = Registro { id: 1, nome: "b".to_string() };
+            let c = Registro { id: 2, nome: "a".to_string() };
+
+            assert_eq!(a, b);
+            assert!(a < c);
+            assert_eq!(a.cmp(&c), std::cmp::Ordering::Less);
+            assert_eq!(*c.key(), 2);
+        }
+
+        #[test]
+        fn derived_key_in_bst() {
+            let mut bst = BST::new();
+            bst.insert(Registro { id: 5, nome: "cinco".to_string() });
+            bst.insert(Registro { id: 3, nome: "três".to_string() });
+            assert!(!bst.insert(Registro { id: 5, nome: "outro".to_string() }));
+
+            assert_eq!(bst.get_by_key(&5).unwrap().nome, "cinco");
+            assert_eq!(bst.remove_by_key(&3).unwrap().nome, "três");
+        }
+
+        #[test]
+        fn derived_tuple_struct_with_generics() {
+            let mut bst = BST::new();
+            bst.insert(Par(vec![1], "b".to_string()));
+            bst.insert(Par(vec![2], "a".to_string()));
+
+            assert_eq!(bst.get_by_key(&"a".to_string()).unwrap().0, vec![2]);
+            assert_eq!(bst.minimum().unwrap().key(), "a");
+        }
+    }
+}
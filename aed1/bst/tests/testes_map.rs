@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use bst::BstMap;
+
+    fn setup_map() -> BstMap<i32, &'static str> {
+        let mut map = BstMap::new();
+        for (k, v) in [(4, "d"), (2, "b"), (6, "f"), (1, "a"), (3, "c"), (5, "e")] {
+            map.insert(k, v);
+        }
+        map
+    }
+
+    #[test]
+    fn insert_get_and_replace() {
+        let mut map = setup_map();
+
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.get(&3), Some(&"c"));
+        assert_eq!(map.get(&10), None);
+        assert_eq!(map.insert(3, "C"), Some("c"));
+        assert_eq!(map.get(&3), Some(&"C"));
+        assert_eq!(map.len(), 6);
+    }
+
+    #[test]
+    fn get_mut_changes_value() {
+        let mut map = setup_map();
+        *map.get_mut(&1).unwrap() = "A";
+
+        assert_eq!(map.get(&1), Some(&"A"));
+        assert!(map.get_mut(&7).is_none());
+    }
+
+    #[test]
+    fn remove() {
+        let mut map = setup_map();
+
+        assert_eq!(map.remove(&4), Some("d"));
+        assert_eq!(map.remove(&4), None);
+        assert!(!map.contains_key(&4));
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.keys().collect::<Vec<_>>(), vec![&1, &2, &3, &5, &6]);
+    }
+
+    #[test]
+    fn iter_in_key_order() {
+        let map = setup_map();
+
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(map.values().copied().collect::<String>(), "abcdef");
+        assert_eq!(map.first(), Some((&1, &"a")));
+        assert_eq!(map.last(), Some((&6, &"f")));
+    }
+
+    #[test]
+    fn range() {
+        let map = setup_map();
+        let keys = |r: Vec<(&i32, &&str)>| r.into_iter().map(|(k, _)| *k).collect::<Vec<_>>();
+
+        assert_eq!(keys(map.range(2..5).collect()), vec![2, 3, 4]);
+        assert_eq!(keys(map.range(2..=5).collect()), vec![2, 3, 4, 5]);
+        assert_eq!(keys(map.range(..3).collect()), vec![1, 2]);
+        assert_eq!(keys(map.range(5..).collect()), vec![5, 6]);
+        assert_eq!(keys(map.range(10..).collect()), Vec::<i32>::new());
+        assert_eq!(keys(map.range(0..1).collect()), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn range_excluded_start() {
+        use std::ops::Bound;
+
+        let map = setup_map();
+        let keys: Vec<i32> = map.range((Bound::Excluded(2), Bound::Included(4))).map(|(k, _)| *k).collect();
+
+        assert_eq!(keys, vec![3, 4]);
+    }
+
+    #[test]
+    fn range_missing_bounds() {
+        let mut map = BstMap::new();
+        for k in [10, 20, 30, 40] {
+            map.insert(k, ());
+        }
+
+        let keys: Vec<i32> = map.range(15..35).map(|(k, _)| *k).collect();
+        assert_eq!(keys, vec![20, 30]);
+    }
+
+    #[test]
+    fn from_iter_last_value_wins() {
+        let map: BstMap<&str, i32> = [("b", 1), ("a", 2), ("b", 3)].into_iter().collect();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&"b"), Some(&3));
+        assert_eq!(format!("{:?}", map), r#"{"a": 2, "b": 3}"#);
+    }
+
+    #[test]
+    fn clear_and_extend() {
+        let mut map = setup_map();
+        map.clear();
+        assert!(map.is_empty());
+
+        map.extend([(1, "x"), (1, "y")]);
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get(&1), Some(&"y"));
+    }
+}
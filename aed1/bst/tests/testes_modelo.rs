@@ -0,0 +1,155 @@
+//  Testes baseados em modelo: sequências aleatórias de operações são
+//  aplicadas à BST e a um BTreeSet (o "oráculo"), e os dois precisam
+//  concordar depois de cada passo.
+//
+//  Não usa dependências externas para poder rodar sob o Miri, que
+//  detecta UB nos blocos unsafe e nodes vazados:
+//      cargo +nightly miri test --test testes_modelo
+//      cargo +nightly miri test --test testes_modelo --features arena
+//  Sob o Miri o número de sequências é reduzido, pois ele é bem mais lento.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use bst::BST;
+
+    const SEQUENCIAS: u64 = if cfg!(miri) { 4 } else { 300 };
+    const OPERACOES: usize = if cfg!(miri) { 60 } else { 400 };
+
+    //  Valores em um intervalo pequeno geram muitas colisões
+    //  (inserções duplicadas e remoções de valores existentes)
+    const VALOR_MAX: u64 = 48;
+
+    /// Gerador xorshift64, determinístico para que falhas sejam reproduzíveis
+    struct Rng(u64);
+
+    impl Rng {
+        fn new(seed: u64) -> Rng {
+            Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+
+        fn value(&mut self) -> i32 {
+            self.below(VALOR_MAX) as i32
+        }
+    }
+
+    fn assert_same(bst: &BST<i32>, model: &BTreeSet<i32>, seed: u64, step: usize) {
+        let ctx = format!("seed {} passo {}", seed, step);
+
+        assert_eq!(bst.walk(), model.iter().collect::<Vec<_>>(), "{}", ctx);
+        assert_eq!(bst.walk_reverse(), model.iter().rev().collect::<Vec<_>>(), "{}", ctx);
+        assert_eq!(bst.total_len(), model.len(), "{}", ctx);
+        assert_eq!(bst.is_empty(), model.is_empty(), "{}", ctx);
+        assert_eq!(bst.minimum(), model.first(), "{}", ctx);
+        assert_eq!(bst.maximum(), model.last(), "{}", ctx);
+        assert!(bst.is_bst(), "{}", ctx);
+    }
+
+    fn run_sequence(seed: u64) {
+        let mut rng = Rng::new(seed);
+        let mut bst = BST::new();
+        let mut model = BTreeSet::new();
+
+        for step in 0..OPERACOES {
+            let value = rng.value();
+
+            match rng.below(10) {
+                0..=3 => {
+                    assert_eq!(bst.insert(value), model.insert(value), "seed {} passo {}", seed, step);
+                }
+                4..=6 => {
+                    assert_eq!(bst.delete(&value), model.remove(&value), "seed {} passo {}", seed, step);
+                }
+                7 => {
+                    assert_eq!(bst.get(&value), model.get(&value), "seed {} passo {}", seed, step);
+                    assert_eq!(bst.search(&value), model.contains(&value), "seed {} passo {}", seed, step);
+                }
+                8 => {
+                    //  remove via cursor e confere para onde ele foi
+                    let mut cursor = bst.cursor_at_mut(&value);
+                    let removed = cursor.remove_current();
+                    let next = cursor.value().copied();
+
+                    assert_eq!(removed.is_some(), model.remove(&value), "seed {} passo {}", seed, step);
+                    if removed.is_some() {
+                        assert_eq!(next, model.range(value..).next().copied(), "seed {} passo {}", seed, step);
+                    }
+                }
+                _ => {
+                    //  navega em ordem a partir de um valor existente
+                    let mut cursor = bst.cursor_at(&value);
+                    cursor.move_next();
+                    if model.contains(&value) {
+                        let expected = model.range(value + 1..).next();
+                        assert_eq!(cursor.value(), expected, "seed {} passo {}", seed, step);
+                    }
+                    cursor.move_prev();
+                    cursor.move_prev();
+                    if model.contains(&value) {
+                        let expected = model.range(..value).next_back();
+                        assert_eq!(cursor.value(), expected, "seed {} passo {}", seed, step);
+                    }
+                }
+            }
+
+            assert_same(&bst, &model, seed, step);
+        }
+    }
+
+    #[test]
+    fn random_operations_match_btreeset() {
+        for seed in 0..SEQUENCIAS {
+            run_sequence(seed);
+        }
+    }
+
+    #[test]
+    fn duplicate_inserts_keep_tree_unchanged() {
+        let mut bst = BST::new();
+        for _ in 0..3 {
+            for value in [5, 3, 8, 1, 4] {
+                bst.insert(value.to_string());
+            }
+        }
+
+        assert_eq!(bst.total_len(), 5);
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn drop_tree_with_heap_values() {
+        let mut rng = Rng::new(42);
+        let mut bst = BST::new();
+        for _ in 0..OPERACOES {
+            bst.insert(format!("{:03}", rng.value()));
+        }
+
+        //  remove parte dos valores e deixa o resto para o Drop
+        for _ in 0..OPERACOES / 4 {
+            bst.delete(&format!("{:03}", rng.value()));
+        }
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn drop_degenerate_tree() {
+        let mut bst = BST::new();
+        for i in 0..if cfg!(miri) { 200 } else { 5_000 } {
+            bst.insert(i);
+        }
+        //  drop iterativo, não pode estourar a pilha
+        drop(bst);
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    use bst::{
+        observer::{Direction::{Left, Right}, Event},
+        BstObserver, Trace, BST,
+    };
+
+    fn setup_tree() -> BST<i32> {
+        let mut bst = BST::new();
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13] {
+            bst.insert(value);
+        }
+        bst
+    }
+
+    fn compare(value: i32, node: i32, ordering: std::cmp::Ordering) -> Event<i32> {
+        Event::Compare { value, node, ordering }
+    }
+
+    #[test]
+    fn insert_into_empty_tree() {
+        let mut bst = BST::new();
+        let mut trace = Trace::new();
+
+        assert!(bst.insert_traced(1, &mut trace));
+        assert_eq!(trace.events(), [Event::Link { value: 1, parent: None }]);
+    }
+
+    #[test]
+    fn insert_records_path() {
+        let mut bst = setup_tree();
+        let mut trace = Trace::new();
+
+        assert!(bst.insert_traced(5, &mut trace));
+        assert_eq!(trace.take(), [
+            compare(5, 8, Less),
+            Event::Descend { node: 8, direction: Left },
+            compare(5, 3, Greater),
+            Event::Descend { node: 3, direction: Right },
+            compare(5, 6, Less),
+            Event::Descend { node: 6, direction: Left },
+            compare(5, 4, Greater),
+            Event::Link { value: 5, parent: Some((4, Right)) },
+        ]);
+
+        //  duplicado: só as comparações, sem ligar um novo node
+        assert!(!bst.insert_traced(6, &mut trace));
+        assert_eq!(trace.take(), [
+            compare(6, 8, Less),
+            Event::Descend { node: 8, direction: Left },
+            compare(6, 3, Greater),
+            Event::Descend { node: 3, direction: Right },
+            compare(6, 6, Equal),
+        ]);
+    }
+
+    #[test]
+    fn delete_with_two_children_splices_sucessor() {
+        let mut bst = setup_tree();
+        let mut trace = Trace::new();
+
+        assert!(bst.delete_traced(&3, &mut trace));
+        assert_eq!(trace.events(), [
+            compare(3, 8, Less),
+            Event::Descend { node: 8, direction: Left },
+            compare(3, 3, Equal),
+            Event::Sucessor { node: 3, sucessor: 4 },
+            //  o sucessor não é filho direto: sai do lugar antes de ocupar o do node
+            Event::Transplant { removed: 4, replacement: None },
+            Event::Transplant { removed: 3, replacement: Some(4) },
+        ]);
+        assert!(bst.is_bst());
+    }
+
+    #[test]
+    fn delete_with_sucessor_as_right_child() {
+        let mut bst = setup_tree();
+        let mut trace = Trace::new();
+
+        bst.delete_traced(&6, &mut trace);
+        assert_eq!(&trace.events()[trace.events().len() - 2..], [
+            Event::Sucessor { node: 6, sucessor: 7 },
+            Event::Transplant { removed: 6, replacement: Some(7) },
+        ]);
+    }
+
+    #[test]
+    fn delete_with_one_child_and_leaf() {
+        let mut bst = setup_tree();
+        let mut trace = Trace::new();
+
+        bst.delete_traced(&10, &mut trace);
+        assert_eq!(trace.take().last(), Some(&Event::Transplant { removed: 10, replacement: Some(14) }));
+
+        bst.delete_traced(&13, &mut trace);
+        assert_eq!(trace.take().last(), Some(&Event::Transplant { removed: 13, replacement: None }));
+    }
+
+    #[test]
+    fn delete_missing_value() {
+        let mut bst = setup_tree();
+        let mut trace = Trace::new();
+
+        assert!(!bst.delete_traced(&99, &mut trace));
+        assert_eq!(trace.events(), [
+            compare(99, 8, Greater),
+            Event::Descend { node: 8, direction: Right },
+            compare(99, 10, Greater),
+            Event::Descend { node: 10, direction: Right },
+            compare(99, 14, Greater),
+        ]);
+    }
+
+    #[test]
+    fn custom_observer_counts_comparisons() {
+        #[derive(Default)]
+        struct Counter(usize);
+
+        impl<T> BstObserver<T> for Counter {
+            fn compare(&mut self, _: &T, _: &T, _: std::cmp::Ordering) {
+                self.0 += 1;
+            }
+        }
+
+        //  inserir em ordem degenera a árvore: i comparações para o i-ésimo valor
+        let mut bst = BST::new();
+        let mut counter = Counter::default();
+        for value in 0..10 {
+            bst.insert_traced(value, &mut counter);
+        }
+
+        assert_eq!(counter.0, (0..10).sum::<usize>());
+    }
+
+    #[test]
+    fn traced_and_plain_operations_build_same_tree() {
+        let mut plain = setup_tree();
+        let mut traced = setup_tree();
+        let mut trace = Trace::new();
+
+        for value in [5, 8, 2, 14, 9] {
+            plain.insert(value);
+            traced.insert_traced(value, &mut trace);
+        }
+        for value in [3, 8, 100] {
+            plain.delete(&value);
+            traced.delete_traced(&value, &mut trace);
+        }
+
+        assert_eq!(plain.to_bytes().unwrap(), traced.to_bytes().unwrap());
+    }
+}
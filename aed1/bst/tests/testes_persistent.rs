@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use bst::{PersistentBst, BST};
+
+    fn from_values(values: &[i32]) -> PersistentBst<i32> {
+        values.iter().fold(PersistentBst::new(), |tree, &v| tree.insert(v))
+    }
+
+    #[test]
+    fn insert_returns_new_version() {
+        let v0 = PersistentBst::new();
+        let v1 = v0.insert(1);
+        let v2 = v1.insert(2);
+
+        assert!(v0.is_empty());
+        assert_eq!(v1.walk(), vec![&1]);
+        assert_eq!(v2.walk(), vec![&1, &2]);
+        assert_eq!(v2.total_len(), 2);
+    }
+
+    #[test]
+    fn insert_duplicate_keeps_version() {
+        let v1 = from_values(&[2, 1, 3]);
+        let v2 = v1.insert(2);
+
+        assert!(v1.ptr_eq(&v2));
+        assert_eq!(v2.total_len(), 3);
+    }
+
+    #[test]
+    fn delete_keeps_old_version() {
+        let v1 = from_values(&[50, 30, 70, 20, 40, 60, 80]);
+        let v2 = v1.delete(&50);
+        let v3 = v2.delete(&20);
+
+        assert_eq!(v1.walk(), vec![&20, &30, &40, &50, &60, &70, &80]);
+        assert_eq!(v2.walk(), vec![&20, &30, &40, &60, &70, &80]);
+        assert_eq!(v3.walk(), vec![&30, &40, &60, &70, &80]);
+        assert_eq!(v3.total_len(), 5);
+        assert!(v3.is_bst());
+    }
+
+    #[test]
+    fn delete_missing_keeps_version() {
+        let v1 = from_values(&[2, 1, 3]);
+        let v2 = v1.delete(&10);
+
+        assert!(v1.ptr_eq(&v2));
+    }
+
+    #[test]
+    fn delete_every_node() {
+        let values = [8, 3, 10, 1, 6, 14, 4, 7, 13];
+        let mut tree = from_values(&values);
+
+        for value in values {
+            tree = tree.delete(&value);
+            assert!(!tree.search(&value));
+            assert!(tree.is_bst());
+        }
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.total_len(), 0);
+    }
+
+    #[test]
+    fn same_shape_as_bst() {
+        let values = [8, 3, 10, 1, 6, 14, 4, 7, 13];
+        let mut tree = from_values(&values);
+        let mut bst = BST::new();
+        for value in values {
+            bst.insert(value);
+        }
+
+        for value in [3, 8, 14] {
+            tree = tree.delete(&value);
+            bst.delete(&value);
+
+            assert_eq!(tree.walk(), bst.walk());
+            assert_eq!(tree.altura(), bst.altura());
+            assert_eq!(tree.leafs_len(), bst.leafs_len());
+            assert_eq!(tree.is_balanced(), bst.is_balanced());
+        }
+    }
+
+    #[test]
+    fn queries() {
+        let tree = from_values(&[5, 3, 8, 1, 4]);
+
+        assert_eq!(tree.get(&4), Some(&4));
+        assert_eq!(tree.get(&7), None);
+        assert_eq!(tree.minimum(), Some(&1));
+        assert_eq!(tree.maximum(), Some(&8));
+        assert_eq!(tree.walk_reverse(), vec![&8, &5, &4, &3, &1]);
+        assert_eq!(tree.altura(), 3);
+        assert_eq!(tree.leafs_len(), 3);
+    }
+
+    #[test]
+    fn is_balanced_checks_both_sides() {
+        assert!(!from_values(&[1, 2, 3]).is_balanced());
+        assert!(!from_values(&[3, 2, 1]).is_balanced());
+        assert!(!from_values(&[2, 1, 3, 4, 5]).is_balanced());
+
+        assert!(from_values(&[2, 1, 3, 4]).is_balanced());
+        assert!(PersistentBst::<i32>::new().is_balanced());
+    }
+
+    #[test]
+    fn walk_range() {
+        let tree = from_values(&[50, 30, 80, 10, 40, 60, 90, 35]);
+
+        assert_eq!(tree.walk_range(&30, &60), vec![&30, &35, &40, &50, &60]);
+        assert_eq!(tree.walk_range(&31, &59), vec![&35, &40, &50]);
+        assert_eq!(tree.walk_range(&0, &1000), tree.walk());
+        assert!(tree.walk_range(&61, &79).is_empty());
+        assert!(tree.walk_range(&60, &30).is_empty());
+        assert!(PersistentBst::<i32>::new().walk_range(&0, &10).is_empty());
+    }
+
+    #[test]
+    fn clone_is_a_snapshot() {
+        let v1 = from_values(&[1, 2, 3]);
+        let snapshot = v1.clone();
+        let v2 = v1.insert(4);
+
+        assert!(snapshot.ptr_eq(&v1));
+        assert!(!snapshot.ptr_eq(&v2));
+        assert_eq!(snapshot.walk(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn drop_many_versions() {
+        let n = if cfg!(miri) { 50 } else { 2_000 };
+        let mut versions = vec![PersistentBst::new()];
+
+        for i in 0..n {
+            let next = versions.last().unwrap().insert(i.to_string());
+            versions.push(next);
+        }
+
+        //  descarta versões intermediárias, mantendo a mais antiga e a mais nova
+        let last = versions.pop().unwrap();
+        versions.truncate(1);
+
+        assert_eq!(last.total_len(), n);
+        assert!(versions[0].is_empty());
+    }
+
+    #[test]
+    fn conversions_preserve_shape() {
+        let mut bst = BST::new();
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13] {
+            bst.insert(value);
+        }
+
+        let persistent = PersistentBst::from(&bst);
+        assert_eq!(persistent.total_len(), 9);
+        assert_eq!(persistent.altura(), bst.altura());
+        assert_eq!(persistent.leafs_len(), bst.leafs_len());
+
+        let back = BST::from(&persistent.insert(5));
+        bst.insert(5);
+        assert_eq!(back.to_bytes().unwrap(), bst.to_bytes().unwrap());
+    }
+}
@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader, path::PathBuf};
+
+    use bst::records::{self, Purchase, RecordError, RecordReader};
+
+    /// O compras.bin gerado por ORI/atv2/gera_arquivo.c
+    fn compras_bin() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../ORI/atv2/compras.bin")
+    }
+
+    fn sample() -> Vec<Purchase> {
+        vec![
+            Purchase::new("manteiga", 1, 4.5),
+            Purchase::new("pao", 1, 7.99),
+            Purchase::new("arroz", 2, 10.95),
+            Purchase::new("feijao", 3, 9.4),
+        ]
+    }
+
+    #[test]
+    fn read_file_from_c_program() {
+        let file = BufReader::new(File::open(compras_bin()).unwrap());
+        let records = records::read_records(file).unwrap();
+
+        assert_eq!(records.len(), 7);
+        assert_eq!(records[0], Purchase::new("manteiga", 1, 4.5));
+        assert_eq!(records[6], Purchase::new("sabao", 2, 3.45));
+        assert_eq!(records[4].to_string(), "nome: carne quantidade: 2 valor: 21.90");
+    }
+
+    #[test]
+    fn write_matches_c_program() {
+        let records = records::read_records(File::open(compras_bin()).unwrap()).unwrap();
+
+        let mut out = Vec::new();
+        records::write_records(&mut out, &records).unwrap();
+
+        assert_eq!(out, std::fs::read(compras_bin()).unwrap());
+    }
+
+    #[test]
+    fn reader_reports_offsets() {
+        let mut out = Vec::new();
+        records::write_records(&mut out, &sample()).unwrap();
+
+        let offsets: Vec<u64> = RecordReader::new(out.as_slice()).map(|r| r.unwrap().0).collect();
+        assert_eq!(offsets, vec![0, 16, 27, 41]);
+    }
+
+    #[test]
+    fn name_may_contain_separator() {
+        let record = Purchase::new("pão|integral", 1, 8.0);
+        let mut out = Vec::new();
+        records::write_record(&mut out, &record).unwrap();
+
+        assert_eq!(records::read_records(out.as_slice()).unwrap(), vec![record]);
+    }
+
+    #[test]
+    fn truncated_record() {
+        let mut out = Vec::new();
+        records::write_records(&mut out, &sample()).unwrap();
+        out.truncate(20);
+
+        let result = records::read_records(out.as_slice());
+        assert!(matches!(result, Err(RecordError::Truncated { offset: 16, expected: 10, found: 3 })));
+    }
+
+    #[test]
+    fn zero_length_record() {
+        let result = records::read_records(&[0u8][..]);
+        assert!(matches!(result, Err(RecordError::BadLength { offset: 0, len: 0 })));
+    }
+
+    #[test]
+    fn missing_fields() {
+        let result = records::read_records(&b"\x07arroz|2"[..]);
+        assert!(matches!(result, Err(RecordError::MissingField { field: "name", .. })));
+
+        let result = records::read_records(&b"\x05arroz"[..]);
+        assert!(matches!(result, Err(RecordError::MissingField { field: "qty", .. })));
+    }
+
+    #[test]
+    fn invalid_fields() {
+        let result = records::read_records(&b"\x0aarroz|x|10"[..]);
+        assert!(matches!(result, Err(RecordError::InvalidField { field: "qty", .. })));
+
+        let result = records::read_records(&b"\x0barroz|-1|10"[..]);
+        assert!(matches!(result, Err(RecordError::InvalidField { field: "qty", .. })));
+
+        let result = records::read_records(&b"\x0barroz|1|NaN"[..]);
+        assert!(matches!(result, Err(RecordError::InvalidField { field: "price", .. })));
+
+        //  mesma regra do formato texto: valores negativos são inválidos
+        let result = records::read_records(&b"\x0barroz|1|-10"[..]);
+        assert!(matches!(result, Err(RecordError::InvalidField { field: "price", .. })));
+    }
+
+    #[test]
+    fn reader_stops_after_error() {
+        let mut reader = RecordReader::new(&b"\x05a|1|1\x05b|x|1\x05c|1|1"[..]);
+
+        assert!(reader.next().unwrap().is_ok());
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn record_too_long() {
+        let record = Purchase::new(&"a".repeat(300), 1, 1.0);
+        let result = records::write_record(&mut Vec::new(), &record);
+        assert!(matches!(result, Err(RecordError::TooLong { .. })));
+    }
+
+    #[test]
+    fn load_bst_by_field() {
+        let bst = records::load_bst(sample(), |r| r.qty);
+
+        assert!(bst.is_balanced());
+        let names: Vec<&str> = bst.walk().iter().map(|(_, _, r)| r.name.as_str()).collect();
+        assert_eq!(names, vec!["manteiga", "pao", "arroz", "feijao"]);
+    }
+
+    #[test]
+    fn load_bst_keeps_identical_records() {
+        let mut records = sample();
+        records.push(Purchase::new("pao", 1, 7.99));
+        records.push(Purchase::new("manteiga", 1, 4.5));
+
+        let bst = records::load_bst(records, |r| r.name.clone());
+
+        let positions: Vec<usize> = bst.walk().iter().map(|(_, i, _)| *i).collect();
+        assert_eq!(positions, vec![2, 3, 0, 5, 1, 4]);
+    }
+
+    #[test]
+    fn load_map_groups_by_field() {
+        let map = records::load_map(sample(), |r| r.qty);
+
+        assert_eq!(map.len(), 3);
+        let ones: Vec<&str> = map.get(&1).unwrap().iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(ones, vec!["manteiga", "pao"]);
+
+        let by_name = records::load_map(sample(), |r| r.name.clone());
+        assert_eq!(by_name.keys().collect::<Vec<_>>(), vec!["arroz", "feijao", "manteiga", "pao"]);
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use bst::BST;
+
+    fn from_values(values: &[i32]) -> BST<i32> {
+        let mut bst = BST::new();
+        for &value in values {
+            bst.insert(value);
+        }
+        bst
+    }
+
+    #[test]
+    fn serialize_as_sorted_sequence() {
+        let bst = from_values(&[4, 2, 6, 1, 3]);
+        assert_eq!(serde_json::to_string(&bst).unwrap(), "[1,2,3,4,6]");
+    }
+
+    #[test]
+    fn round_trip_rebuilds_balanced() {
+        //  árvore degenerada, altura 7
+        let bst = from_values(&[1, 2, 3, 4, 5, 6, 7]);
+
+        let json = serde_json::to_string(&bst).unwrap();
+        let loaded: BST<i32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded, bst);
+        assert_eq!(loaded.altura(), 3);
+        assert!(loaded.is_balanced());
+    }
+
+    #[test]
+    fn round_trip_strings() {
+        let mut bst = BST::new();
+        for name in ["manteiga", "pao", "arroz", "feijao"] {
+            bst.insert(name.to_string());
+        }
+
+        let json = serde_json::to_string(&bst).unwrap();
+        let loaded: BST<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.walk(), bst.walk());
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let bst: BST<i32> = BST::new();
+        let json = serde_json::to_string(&bst).unwrap();
+
+        assert_eq!(json, "[]");
+        assert!(serde_json::from_str::<BST<i32>>(&json).unwrap().is_empty());
+    }
+
+    #[test]
+    fn deserialize_unsorted_with_duplicates() {
+        let loaded: BST<i32> = serde_json::from_str("[5, 3, 5, 1, 3]").unwrap();
+
+        assert_eq!(loaded.walk(), vec![&1, &3, &5]);
+        assert!(loaded.is_bst());
+    }
+
+    //  f64 em que um valor negativo representa "sem nota" (NaN)
+    #[derive(Debug, PartialEq, PartialOrd)]
+    struct Nota(f64);
+
+    impl<'de> serde::Deserialize<'de> for Nota {
+        fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let value = f64::deserialize(deserializer)?;
+            Ok(Nota(if value < 0.0 { f64::NAN } else { value }))
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_incomparable_values() {
+        let loaded: BST<Nota> = serde_json::from_str("[7.5, 2.0, 9.0]").unwrap();
+        assert!(loaded.is_bst());
+
+        let err = serde_json::from_str::<BST<Nota>>("[7.5, -1, 2.0, 9.0]").unwrap_err();
+        assert!(err.to_string().contains("não podem ser comparados"));
+    }
+
+    #[test]
+    fn deserialize_rejects_non_sequence() {
+        assert!(serde_json::from_str::<BST<i32>>("{\"a\": 1}").is_err());
+        assert!(serde_json::from_str::<BST<i32>>("[1, \"dois\"]").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use bst::{SharedBst, BST};
+
+    fn setup_shared() -> SharedBst<i32> {
+        let shared = SharedBst::new();
+        for value in [4, 2, 6, 1, 3, 5, 7] {
+            shared.insert(value);
+        }
+        shared
+    }
+
+    #[test]
+    fn insert_and_delete() {
+        let shared = setup_shared();
+
+        assert!(!shared.insert(4));
+        assert!(shared.delete(&4));
+        assert!(!shared.delete(&4));
+        assert_eq!(shared.total_len(), 6);
+        assert!(!shared.search(&4));
+    }
+
+    #[test]
+    fn snapshot_is_not_affected_by_later_writes() {
+        let shared = setup_shared();
+        let snapshot = shared.snapshot();
+
+        shared.insert(10);
+        shared.delete(&1);
+
+        assert_eq!(snapshot.walk(), vec![&1, &2, &3, &4, &5, &6, &7]);
+        assert_eq!(shared.snapshot().walk(), vec![&2, &3, &4, &5, &6, &7, &10]);
+    }
+
+    #[test]
+    fn write_while_iterating_snapshot() {
+        let shared = setup_shared();
+        let snapshot = shared.snapshot();
+        let mut cursor = snapshot.cursor_front();
+
+        //  o snapshot não segura lock, a escrita não pode bloquear
+        while let Some(&value) = cursor.value() {
+            shared.delete(&value);
+            cursor.move_next();
+        }
+
+        assert!(shared.is_empty());
+        assert_eq!(snapshot.total_len(), 7);
+    }
+
+    #[test]
+    fn write_closure_returns_value() {
+        let shared = SharedBst::from(BST::new());
+        let altura = shared.write(|bst| {
+            for i in 1..=3 {
+                bst.insert(i);
+            }
+            bst.altura()
+        });
+
+        assert_eq!(altura, 3);
+    }
+
+    #[test]
+    fn clear_keeps_old_snapshots() {
+        let shared = setup_shared();
+        let snapshot = shared.snapshot();
+
+        shared.clear();
+
+        assert!(shared.is_empty());
+        assert_eq!(snapshot.total_len(), 7);
+    }
+
+    #[test]
+    fn concurrent_readers_and_writer() {
+        let shared = Arc::new(SharedBst::new());
+        let n = if cfg!(miri) { 20 } else { 500 };
+
+        let writer = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                for i in 0..n {
+                    shared.insert(i);
+                }
+            })
+        };
+
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = Arc::clone(&shared);
+                thread::spawn(move || {
+                    for _ in 0..n / 10 {
+                        //  cada snapshot é sempre uma árvore válida e completa
+                        let snapshot = shared.snapshot();
+                        let values = snapshot.walk();
+                        assert!(snapshot.is_bst());
+                        assert!(values.iter().enumerate().all(|(i, &&v)| v == i as i32));
+                    }
+                })
+            })
+            .collect();
+
+        writer.join().unwrap();
+        for reader in readers {
+            reader.join().unwrap();
+        }
+
+        assert_eq!(shared.total_len(), n as usize);
+    }
+}
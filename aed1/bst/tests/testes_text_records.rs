@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::BufReader, path::PathBuf};
+
+    use bst::{
+        records::Purchase,
+        text_records::{self, TextError},
+    };
+
+    /// O compra.txt lido pelo ex1.c, com \r\n no fim das linhas
+    fn compra_txt() -> BufReader<File> {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../ORI/atv1/compra.txt");
+        BufReader::new(File::open(path).unwrap())
+    }
+
+    fn names(items: Vec<&Purchase>) -> Vec<&str> {
+        items.into_iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test]
+    fn read_file_from_c_program() {
+        let purchases = text_records::read_purchases(compra_txt()).unwrap();
+
+        assert_eq!(purchases.len(), 7);
+        assert_eq!(purchases[0], Purchase::new("manteiga", 1, 4.5));
+        assert_eq!(purchases[4], Purchase::new("carne", 2, 21.9));
+    }
+
+    #[test]
+    fn total_matches_c_program() {
+        let items = text_records::load_text(compra_txt()).unwrap();
+
+        //  o ex1.c imprime "O preço total é R$: 106.24"
+        assert_eq!(format!("{:.2}", text_records::total(&items)), "106.24");
+    }
+
+    #[test]
+    fn sorted_by_name_and_spend() {
+        let items = text_records::load_text(compra_txt()).unwrap();
+
+        assert_eq!(
+            names(text_records::by_name(&items)),
+            ["arroz", "carne", "detergente", "feijao", "manteiga", "pao", "sabao"]
+        );
+        assert_eq!(
+            names(text_records::by_spend(&items)),
+            ["carne", "arroz", "feijao", "pao", "sabao", "detergente", "manteiga"]
+        );
+    }
+
+    #[test]
+    fn repeated_names_are_aggregated() {
+        let text = "pao 2 1.00\nleite 1 5.00\npao 3 2.00\n";
+        let items = text_records::load_text(text.as_bytes()).unwrap();
+
+        assert_eq!(items.len(), 2);
+        let pao = items.get(&"pao".to_string()).unwrap();
+        assert_eq!(pao.qty, 5);
+        assert!((pao.total() - 8.0).abs() < 1e-9);
+        assert!((text_records::total(&items) - 13.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quantity_overflow() {
+        let text = "pao 3000000000 1\n\narroz 1 1\npao 3000000000 1\n";
+        let result = text_records::load_text(text.as_bytes());
+        assert!(matches!(result, Err(TextError::QtyOverflow { line: 4, ref name }) if name == "pao"));
+
+        let purchases = text_records::read_purchases(text.as_bytes()).unwrap();
+        let result = text_records::aggregate(purchases);
+        assert!(matches!(result, Err(TextError::QtyOverflow { line: 3, .. })));
+    }
+
+    #[test]
+    fn spend_ties_keep_name_order() {
+        let text = "b 1 2\na 2 1\nc 1 5\n";
+        let items = text_records::load_text(text.as_bytes()).unwrap();
+
+        assert_eq!(names(text_records::by_spend(&items)), ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let text = "\npao 1 7.99\r\n   \n\narroz 2 10.95";
+        let purchases = text_records::read_purchases(text.as_bytes()).unwrap();
+
+        assert_eq!(purchases, [Purchase::new("pao", 1, 7.99), Purchase::new("arroz", 2, 10.95)]);
+    }
+
+    #[test]
+    fn errors_have_line_numbers() {
+        let result = text_records::read_purchases("pao 1 7.99\narroz 2\n".as_bytes());
+        assert!(matches!(result, Err(TextError::MissingField { line: 2, field: "price" })));
+
+        let result = text_records::read_purchases("\n\narroz\n".as_bytes());
+        assert!(matches!(result, Err(TextError::MissingField { line: 3, field: "qty" })));
+
+        let result = text_records::read_purchases("pao de forma 1 7.99\n".as_bytes());
+        assert!(matches!(result, Err(TextError::InvalidField { line: 1, field: "qty", .. })));
+
+        let result = text_records::read_purchases("pao 1 7.99 x\n".as_bytes());
+        assert!(matches!(result, Err(TextError::ExtraField { line: 1, .. })));
+    }
+
+    #[test]
+    fn invalid_values() {
+        for (line, field) in [("pao -1 2", "qty"), ("pao 1.5 2", "qty"), ("pao 1 abc", "price"), ("pao 1 -2", "price"), ("pao 1 inf", "price")] {
+            let result = text_records::parse_line(line, 7);
+            assert!(matches!(result, Err(TextError::InvalidField { line: 7, field: f, .. }) if f == field), "{}", line);
+        }
+    }
+
+    #[test]
+    fn error_message() {
+        let err = text_records::parse_line("arroz 2", 4).unwrap_err();
+        assert_eq!(err.to_string(), "linha 4: falta o campo price");
+    }
+}
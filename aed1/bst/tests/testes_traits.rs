@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::hash_map::DefaultHasher,
+        hash::{Hash, Hasher},
+        sync::Arc,
+        thread,
+    };
+
+    use bst::{Cursor, BST};
+
+    fn from_values(values: &[i32]) -> BST<i32> {
+        let mut bst = BST::new();
+        for &value in values {
+            bst.insert(value);
+        }
+        bst
+    }
+
+    /// Valores em pré-ordem, para comparar o formato de duas árvores
+    fn preorder(bst: &BST<i32>) -> Vec<(i32, usize)> {
+        fn visit(cursor: Option<Cursor<'_, i32>>, out: &mut Vec<(i32, usize)>) {
+            let Some(cursor) = cursor else {return;};
+            out.push((*cursor.value().unwrap(), cursor.depth().unwrap()));
+            visit(cursor.left(), out);
+            visit(cursor.right(), out);
+        }
+
+        let mut out = Vec::new();
+        if !bst.is_empty() {
+            visit(Some(bst.cursor_root()), &mut out);
+        }
+        out
+    }
+
+    fn hash_of(bst: &BST<i32>) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        bst.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn bst_is_send_and_sync() {
+        assert_send_sync::<BST<i32>>();
+        assert_send_sync::<BST<String>>();
+        assert_send_sync::<Cursor<'_, i32>>();
+    }
+
+    #[test]
+    fn clone_preserves_shape() {
+        let bst = from_values(&[8, 3, 10, 1, 6, 14, 4, 7, 13]);
+        let clone = bst.clone();
+
+        assert_eq!(preorder(&clone), preorder(&bst));
+        assert_eq!(clone.altura(), bst.altura());
+        assert_eq!(clone.leafs_len(), bst.leafs_len());
+    }
+
+    #[test]
+    fn clone_preserves_skewed_shape() {
+        let bst = from_values(&[1, 2, 3, 4, 5]);
+        let clone = bst.clone();
+
+        assert_eq!(clone.altura(), 5);
+        assert_eq!(preorder(&clone), preorder(&bst));
+    }
+
+    #[test]
+    fn clone_is_independent() {
+        let bst = from_values(&[2, 1, 3]);
+        let mut clone = bst.clone();
+
+        clone.delete(&2);
+        clone.insert(10);
+
+        assert_eq!(bst.walk(), vec![&1, &2, &3]);
+        assert_eq!(clone.walk(), vec![&1, &3, &10]);
+    }
+
+    #[test]
+    fn clone_empty_tree() {
+        let bst: BST<i32> = BST::new();
+        assert!(bst.clone().is_empty());
+    }
+
+    #[test]
+    fn eq_ignores_shape() {
+        let balanced = from_values(&[2, 1, 3]);
+        let skewed = from_values(&[1, 2, 3]);
+
+        assert_ne!(preorder(&balanced), preorder(&skewed));
+        assert_eq!(balanced, skewed);
+        assert_eq!(hash_of(&balanced), hash_of(&skewed));
+    }
+
+    #[test]
+    fn ne_with_different_contents() {
+        assert_ne!(from_values(&[1, 2, 3]), from_values(&[1, 2]));
+        assert_ne!(from_values(&[1, 2, 3]), from_values(&[1, 2, 4]));
+        assert_ne!(hash_of(&from_values(&[1, 2])), hash_of(&from_values(&[1, 2, 4])));
+    }
+
+    #[test]
+    fn debug_prints_values_in_order() {
+        let bst = from_values(&[2, 3, 1]);
+        assert_eq!(format!("{:?}", bst), "{1, 2, 3}");
+        assert_eq!(format!("{:?}", BST::<i32>::new()), "{}");
+    }
+
+    #[test]
+    fn move_tree_to_worker_thread() {
+        let bst = from_values(&[5, 3, 8]);
+
+        let handle = thread::spawn(move || {
+            let mut bst = bst;
+            bst.insert(1);
+            bst
+        });
+
+        assert_eq!(handle.join().unwrap().walk(), vec![&1, &3, &5, &8]);
+    }
+
+    #[test]
+    fn share_tree_between_threads() {
+        let bst = Arc::new(from_values(&[4, 2, 6, 1, 3, 5, 7]));
+
+        let handles: Vec<_> = (1..=7)
+            .map(|value| {
+                let bst = Arc::clone(&bst);
+                thread::spawn(move || bst.search(&value))
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+    }
+}
@@ -0,0 +1,152 @@
+/*
+ *  #[derive(BstKey)] para structs que são guardadas na BST por uma chave.
+ *  O campo marcado com #[key] vira a chave do registro, e a comparação
+ *  passa a usar apenas ele:
+ *
+ *      #[derive(BstKey)]
+ *      struct Registro {
+ *          #[key]
+ *          id: i32,
+ *          nome: String,
+ *      }
+ *
+ *  gera impl bst::BstKey (com Key = i32) e PartialEq, Eq, PartialOrd e Ord
+ *  comparando só o id, o que antes era escrito à mão em cada registro.
+ *  Use pela feature "derive" do crate bst, que reexporta esta macro.
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index, Member};
+
+#[proc_macro_derive(BstKey, attributes(key))]
+pub fn derive_bst_key(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let (member, key_type) = key_field(&input)?;
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::bst::BstKey for #name #type_generics #where_clause {
+            type Key = #key_type;
+
+            fn key(&self) -> &Self::Key {
+                &self.#member
+            }
+        }
+
+        impl #impl_generics ::core::cmp::PartialEq for #name #type_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                self.#member == other.#member
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Eq for #name #type_generics #where_clause {}
+
+        impl #impl_generics ::core::cmp::PartialOrd for #name #type_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::core::cmp::Ord for #name #type_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.#member, &other.#member)
+            }
+        }
+    })
+}
+
+//  O campo marcado com #[key]: deve haver exatamente um
+fn key_field(input: &DeriveInput) -> syn::Result<(Member, &syn::Type)> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(input.ident.span(), "BstKey só pode ser derivado para structs"));
+    };
+    if let Fields::Unit = data.fields {
+        return Err(Error::new(input.ident.span(), "BstKey precisa de um campo marcado com #[key]"));
+    }
+
+    let mut found = None;
+    for (i, field) in data.fields.iter().enumerate() {
+        let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("key")) else {continue;};
+
+        //  #[key] não aceita argumentos
+        attr.meta.require_path_only()?;
+        if found.is_some() {
+            return Err(Error::new(attr.span(), "apenas um campo pode ser marcado com #[key]"));
+        }
+
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        };
+        found = Some((member, &field.ty));
+    }
+
+    found.ok_or_else(|| Error::new(input.ident.span(), "BstKey precisa de um campo marcado com #[key]"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_str(input: TokenStream2) -> Result<String, String> {
+        let input: DeriveInput = syn::parse2(input).unwrap();
+        expand(input).map(|t| t.to_string()).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn named_field() {
+        let out = expand_str(quote! { struct Registro { #[key] id: i32, nome: String } }).unwrap();
+
+        assert!(out.contains("type Key = i32"));
+        assert!(out.contains("& self . id"));
+    }
+
+    #[test]
+    fn tuple_field() {
+        let out = expand_str(quote! { struct Par(String, #[key] u64); }).unwrap();
+
+        assert!(out.contains("type Key = u64"));
+        assert!(out.contains("& self . 1"));
+    }
+
+    #[test]
+    fn generics_are_kept() {
+        let out = expand_str(quote! { struct Item<T: Clone> { #[key] id: u32, valor: T } }).unwrap();
+        assert!(out.contains("impl < T : Clone > :: bst :: BstKey for Item < T >"));
+    }
+
+    #[test]
+    fn missing_key() {
+        let err = expand_str(quote! { struct Registro { id: i32 } }).unwrap_err();
+        assert_eq!(err, "BstKey precisa de um campo marcado com #[key]");
+
+        let err = expand_str(quote! { struct Vazio; }).unwrap_err();
+        assert_eq!(err, "BstKey precisa de um campo marcado com #[key]");
+    }
+
+    #[test]
+    fn two_keys() {
+        let err = expand_str(quote! { struct Registro { #[key] id: i32, #[key] idade: i32 } }).unwrap_err();
+        assert_eq!(err, "apenas um campo pode ser marcado com #[key]");
+    }
+
+    #[test]
+    fn key_with_arguments() {
+        assert!(expand_str(quote! { struct Registro { #[key(x)] id: i32 } }).is_err());
+    }
+
+    #[test]
+    fn enums_are_rejected() {
+        let err = expand_str(quote! { enum Registro { A, B } }).unwrap_err();
+        assert_eq!(err, "BstKey só pode ser derivado para structs");
+    }
+}
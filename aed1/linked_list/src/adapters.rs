@@ -0,0 +1,363 @@
+/*
+ *  Pilha e fila sobre a LinkedList de linked_list_simple.rs, com
+ *  todas as operações em O(1):
+ *      - Stack: push e pop no head (LIFO)
+ *      - Queue: push no tail e pop no head (FIFO), graças ao ponteiro tail
+ *
+ *  BoundedStack e BoundedQueue têm uma capacidade máxima; push em uma
+ *  delas cheia devolve CapacityError com o valor que não coube.
+ *
+ *  iter() e into_iter() devolvem os iteradores da LinkedList
+ *  (Iter e IntoIter, exportados na raiz do crate).
+*/
+
+use std::{error::Error, fmt};
+
+use crate::{IntoIter, Iter, LinkedList};
+
+/// push() em uma pilha ou fila cheia <br>
+/// O valor recusado pode ser recuperado com into_inner()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityError<T> {
+    pub value: T,
+    pub capacity: usize,
+}
+
+impl<T> CapacityError<T> {
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "capacidade de {} valores atingida", self.capacity)
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
+
+/// Pilha (LIFO): o último valor adicionado é o primeiro a sair
+pub struct Stack<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+impl<T> Stack<T> {
+    pub fn new() -> Stack<T> {
+        Stack { list: LinkedList::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Coloca value no topo
+    pub fn push(&mut self, value: T) {
+        self.list.push_front(value);
+    }
+
+    /// Tira o valor do topo
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Valor do topo, sem tirá-lo
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(0)
+    }
+
+    /// Percorre do topo para a base, na ordem em que pop() devolveria
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.list = LinkedList::new();
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack = Stack::new();
+        stack.extend(iter);
+        return stack;
+    }
+}
+
+/// Empilha os valores em ordem: o último fica no topo
+impl<T> Extend<T> for Stack<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for Stack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Stack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Fila (FIFO): o primeiro valor adicionado é o primeiro a sair
+pub struct Queue<T> {
+    list: LinkedList<T>,
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Queue<T> {
+        Queue { list: LinkedList::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Coloca value no fim da fila
+    pub fn push(&mut self, value: T) {
+        self.list.push_back(value);
+    }
+
+    /// Tira o valor do começo da fila
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    /// Valor do começo da fila, sem tirá-lo
+    pub fn peek(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.list.get_mut(0)
+    }
+
+    /// Percorre do começo para o fim, na ordem em que pop() devolveria
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.list = LinkedList::new();
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Queue { list: iter.into_iter().collect() }
+    }
+}
+
+impl<T> Extend<T> for Queue<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.list.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Queue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.list.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Queue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Stack com no máximo capacity valores
+pub struct BoundedStack<T> {
+    stack: Stack<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedStack<T> {
+    pub fn new(capacity: usize) -> BoundedStack<T> {
+        BoundedStack { stack: Stack::new(), capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.stack.len() >= self.capacity
+    }
+
+    /// Coloca value no topo <br>
+    /// **Err** com value, se a pilha estiver cheia
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError { value, capacity: self.capacity });
+        }
+
+        self.stack.push(value);
+        return Ok(());
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.stack.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.stack.peek()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.stack.peek_mut()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.stack.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+}
+
+impl<T> IntoIterator for BoundedStack<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.stack.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundedStack<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Queue com no máximo capacity valores
+pub struct BoundedQueue<T> {
+    queue: Queue<T>,
+    capacity: usize,
+}
+
+impl<T> BoundedQueue<T> {
+    pub fn new(capacity: usize) -> BoundedQueue<T> {
+        BoundedQueue { queue: Queue::new(), capacity }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.queue.len() >= self.capacity
+    }
+
+    /// Coloca value no fim da fila <br>
+    /// **Err** com value, se a fila estiver cheia
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError { value, capacity: self.capacity });
+        }
+
+        self.queue.push(value);
+        return Ok(());
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.queue.pop()
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.queue.peek()
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.queue.peek_mut()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.queue.iter()
+    }
+
+    pub fn clear(&mut self) {
+        self.queue.clear();
+    }
+}
+
+impl<T> IntoIterator for BoundedQueue<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        self.queue.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundedQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
@@ -0,0 +1,486 @@
+/*
+ *  Lista duplamente encadeada circular, como a de
+ *  circular_double_linked_list.cpp: o next do tail é o head e o prev
+ *  do head é o tail, então a lista guarda só o head (o tail é head.prev)
+ *  e todo node tem prev e next.
+ *
+ *  Girar a lista é só mudar qual node é o head, sem mover valores.
+ *  Os cursores dão voltas na lista sem fim; já iter() passa uma vez
+ *  por cada valor, começando no head.
+ *
+ *  Como em doubly_linked_list.rs, os nodes são Box vazados que voltam
+ *  a ser Box em unlink, e a navegação usa cópias dos NonNull.
+*/
+
+use std::{fmt, marker::PhantomData, ptr::NonNull};
+
+struct Node<T> {
+    value: T,
+    prev: NonNull<Node<T>>,
+    next: NonNull<Node<T>>,
+}
+
+pub struct CircularList<T> {
+    head: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        CircularList::new()
+    }
+}
+
+impl<T> CircularList<T> {
+    pub fn new() -> CircularList<T> {
+        CircularList {
+            head: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail().map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Adiciona value no fim, entre o tail e o head
+    pub fn push_back(&mut self, value: T) {
+        match self.head {
+            Some(head) => unsafe { self.link_before(head, value) },
+            None => self.link_first(value),
+        };
+    }
+
+    /// Adiciona value no começo: o mesmo lugar de push_back(), mas o novo node vira o head
+    pub fn push_front(&mut self, value: T) {
+        self.push_back(value);
+        self.head = self.tail();
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        return Some(unsafe { self.unlink(head) });
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail()?;
+        return Some(unsafe { self.unlink(tail) });
+    }
+
+    /// Gira a lista uma posição para a esquerda, em O(1): o segundo valor vira o head
+    pub fn rotate_left_one(&mut self) {
+        if let Some(head) = self.head {
+            self.head = Some(unsafe { head.as_ref().next });
+        }
+    }
+
+    /// Gira a lista uma posição para a direita, em O(1): o tail vira o head
+    pub fn rotate_right_one(&mut self) {
+        self.head = self.tail();
+    }
+
+    /// Gira a lista n posições para a esquerda: o valor na posição n vira o head <br>
+    /// Não é O(1): n é reduzido a n % len e o head anda uma posição por vez
+    /// pelo lado mais curto, então custa O(min(n, len - n)).
+    /// Para girar em O(1), use rotate_left_one() e rotate_right_one()
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.len == 0 {return;}
+
+        let n = n % self.len;
+        if n <= self.len / 2 {
+            for _ in 0..n {
+                self.rotate_left_one();
+            }
+        } else {
+            for _ in n..self.len {
+                self.rotate_right_one();
+            }
+        }
+    }
+
+    /// Gira a lista n posições para a direita: os n últimos valores passam para o começo <br>
+    /// Assim como rotate_left(), custa O(min(n, len - n))
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.len == 0 {return;}
+
+        self.rotate_left(self.len - n % self.len);
+    }
+
+    /// Problema de Josephus: a partir do head, conta k valores e remove o
+    /// k-ésimo, seguindo a contagem a partir do próximo, até sobrar um <br>
+    /// Devolve os valores na ordem em que foram removidos; o que sobra fica no head
+    ///
+    /// # Panics
+    /// Se k for 0
+    pub fn remove_every_kth(&mut self, k: usize) -> Vec<T> {
+        assert!(k > 0, "k precisa ser maior que 0");
+
+        let mut removed = Vec::with_capacity(self.len.saturating_sub(1));
+        let mut cursor = self.cursor_front_mut();
+
+        while cursor.list.len > 1 {
+            for _ in 1..k {
+                cursor.move_next();
+            }
+            removed.extend(cursor.remove_current());
+        }
+
+        //  o cursor está no sobrevivente
+        let survivor = cursor.current;
+        self.head = survivor;
+        return removed;
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail(),
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Cursor no head, que dá voltas na lista
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor { current: self.head, list: self }
+    }
+
+    /// Cursor no head que pode alterar a lista, e que dá voltas nela
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { current: self.head, list: self }
+    }
+
+    fn tail(&self) -> Option<NonNull<Node<T>>> {
+        self.head.map(|head| unsafe { head.as_ref().prev })
+    }
+
+    //  O primeiro node aponta para si mesmo nos dois sentidos
+    fn link_first(&mut self, value: T) -> NonNull<Node<T>> {
+        let node = Node { value, prev: NonNull::dangling(), next: NonNull::dangling() };
+        let mut node = NonNull::from_mut(Box::leak(Box::new(node)));
+
+        unsafe {
+            node.as_mut().prev = node;
+            node.as_mut().next = node;
+        }
+        self.head = Some(node);
+        self.len = 1;
+        return node;
+    }
+
+    //  Cria um node com value entre at.prev e at
+    unsafe fn link_before(&mut self, mut at: NonNull<Node<T>>, value: T) -> NonNull<Node<T>> {
+        unsafe {
+            let mut prev = at.as_ref().prev;
+            let node = Node { value, prev, next: at };
+            let node = NonNull::from_mut(Box::leak(Box::new(node)));
+
+            prev.as_mut().next = node;
+            at.as_mut().prev = node;
+            self.len += 1;
+            return node;
+        }
+    }
+
+    //  Tira node da lista, desalocando-o, e devolve o seu valor
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> T {
+        self.len -= 1;
+
+        if self.len == 0 {
+            self.head = None;
+        } else {
+            unsafe {
+                let (mut prev, mut next) = (node.as_ref().prev, node.as_ref().next);
+                prev.as_mut().next = next;
+                next.as_mut().prev = prev;
+
+                if self.head == Some(node) {
+                    self.head = Some(next);
+                }
+            }
+        }
+
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        return node.value;
+    }
+}
+
+impl<T> Drop for CircularList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for CircularList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = CircularList::new();
+        list.extend(iter);
+        return list;
+    }
+}
+
+impl<T> Extend<T> for CircularList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Passa uma vez por cada valor, do head ao tail <br>
+/// len controla o fim, já que na lista circular não há um next None
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &*self.head?.as_ptr() };
+        self.head = Some(node.next);
+        self.len -= 1;
+        return Some(&node.value);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &*self.tail?.as_ptr() };
+        self.tail = Some(node.prev);
+        self.len -= 1;
+        return Some(&node.value);
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &mut *self.head?.as_ptr() };
+        self.head = Some(node.next);
+        self.len -= 1;
+        return Some(&mut node.value);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &mut *self.tail?.as_ptr() };
+        self.tail = Some(node.prev);
+        self.len -= 1;
+        return Some(&mut node.value);
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consome a lista, devolvendo os valores a partir do head
+pub struct IntoIter<T>(CircularList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for CircularList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a CircularList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut CircularList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Cursor que dá voltas na lista: depois do tail vem o head de novo <br>
+/// Só fica sem valor (None) se a lista estiver vazia
+pub struct Cursor<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    list: &'a CircularList<T>,
+}
+
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Cursor { current: self.current, list: self.list }
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    pub fn current(&self) -> Option<&'a T> {
+        self.current.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn move_next(&mut self) {
+        self.current = self.current.map(|node| unsafe { node.as_ref().next });
+    }
+
+    pub fn move_prev(&mut self) {
+        self.current = self.current.map(|node| unsafe { node.as_ref().prev });
+    }
+
+    pub fn list(&self) -> &'a CircularList<T> {
+        self.list
+    }
+}
+
+/// Iterar um Cursor nunca termina (a não ser com a lista vazia),
+/// então deve ser limitado com take() ou parecido
+impl<'a, T> Iterator for Cursor<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let value = self.current();
+        self.move_next();
+        return value;
+    }
+}
+
+/// Cursor que dá voltas na lista e pode alterá-la na posição em que está
+pub struct CursorMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    list: &'a mut CircularList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn move_next(&mut self) {
+        self.current = self.current.map(|node| unsafe { node.as_ref().next });
+    }
+
+    pub fn move_prev(&mut self) {
+        self.current = self.current.map(|node| unsafe { node.as_ref().prev });
+    }
+
+    /// Insere value depois do cursor <br>
+    /// Com a lista vazia, o cursor passa para o novo valor
+    pub fn insert_after(&mut self, value: T) {
+        match self.current {
+            Some(node) => unsafe {
+                self.list.link_before(node.as_ref().next, value);
+            },
+            None => self.current = Some(self.list.link_first(value)),
+        }
+    }
+
+    /// Insere value antes do cursor; se o cursor está no head, o novo valor
+    /// fica no fim da lista <br>
+    /// Com a lista vazia, o cursor passa para o novo valor
+    pub fn insert_before(&mut self, value: T) {
+        match self.current {
+            Some(node) => unsafe {
+                self.list.link_before(node, value);
+            },
+            None => self.current = Some(self.list.link_first(value)),
+        }
+    }
+
+    /// Remove o valor do cursor, devolvendo-o <br>
+    /// O cursor passa para o valor seguinte
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+
+        self.current = match self.list.len {
+            1 => None,
+            _ => Some(unsafe { node.as_ref().next }),
+        };
+        return Some(unsafe { self.list.unlink(node) });
+    }
+
+    /// Faz do valor do cursor o head da lista
+    pub fn make_head(&mut self) {
+        self.list.head = self.current;
+    }
+}
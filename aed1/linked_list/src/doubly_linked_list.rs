@@ -0,0 +1,479 @@
+/*
+ *  Lista duplamente encadeada, como a de double_linked_list.cpp:
+ *  cada node aponta para o anterior e para o próximo, e a lista
+ *  guarda head e tail, então push e pop são O(1) nas duas pontas.
+ *
+ *  CursorMut percorre a lista nos dois sentidos e altera a lista
+ *  na posição em que está (insert_before/after, remove_current, splice).
+ *  Além do último node há uma posição "fantasma", fora da lista,
+ *  que liga o tail de volta ao head:
+ *
+ *      fantasma <-> head <-> ... <-> tail <-> fantasma
+ *
+ *  Os nodes são Box vazados (Box::leak) e só voltam a ser Box, para
+ *  serem desalocados, quando saem da lista (Box::from_raw em unlink).
+ *  A navegação usa sempre cópias dos NonNull, e não referências, para
+ *  que os ponteiros guardados nos outros nodes continuem válidos.
+*/
+
+use std::{fmt, marker::PhantomData, ptr::NonNull};
+
+struct Node<T> {
+    value: T,
+    prev: Option<NonNull<Node<T>>>,
+    next: Option<NonNull<Node<T>>>,
+}
+
+pub struct DoublyLinkedList<T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    //  a lista é dona dos nodes, como se guardasse Box<Node<T>>
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+impl<T> Default for DoublyLinkedList<T> {
+    fn default() -> Self {
+        DoublyLinkedList::new()
+    }
+}
+
+impl<T> DoublyLinkedList<T> {
+    pub fn new() -> DoublyLinkedList<T> {
+        DoublyLinkedList {
+            head: None,
+            tail: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    pub fn push_front(&mut self, value: T) {
+        let node = Self::new_node(value);
+        unsafe { self.link_between(None, self.head, node, node, 1) };
+    }
+
+    pub fn push_back(&mut self, value: T) {
+        let node = Self::new_node(value);
+        unsafe { self.link_between(self.tail, None, node, node, 1) };
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        return Some(unsafe { self.unlink(head) }.value);
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        return Some(unsafe { self.unlink(tail) }.value);
+    }
+
+    /// Remove todos os valores
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Move todos os valores de other para o fim desta lista, em O(1) <br>
+    /// other fica vazia
+    pub fn append(&mut self, other: &mut DoublyLinkedList<T>) {
+        self.cursor_ghost_mut().splice_before(std::mem::take(other));
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Cursor no primeiro valor (no fantasma, se a lista está vazia)
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { current: self.head, index: 0, list: self }
+    }
+
+    /// Cursor no último valor (no fantasma, se a lista está vazia)
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let index = self.len.saturating_sub(1);
+        CursorMut { current: self.tail, index, list: self }
+    }
+
+    fn cursor_ghost_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { current: None, index: self.len, list: self }
+    }
+
+    fn new_node(value: T) -> NonNull<Node<T>> {
+        let node = Node { value, prev: None, next: None };
+        return NonNull::from_mut(Box::leak(Box::new(node)));
+    }
+
+    //  Liga a sequência first..=last, com count nodes, entre prev e next,
+    //  que precisam ser vizinhos (None: a ponta da lista)
+    unsafe fn link_between(
+        &mut self,
+        prev: Option<NonNull<Node<T>>>,
+        next: Option<NonNull<Node<T>>>,
+        mut first: NonNull<Node<T>>,
+        mut last: NonNull<Node<T>>,
+        count: usize,
+    ) {
+        unsafe {
+            first.as_mut().prev = prev;
+            last.as_mut().next = next;
+
+            match prev {
+                Some(mut p) => p.as_mut().next = Some(first),
+                None => self.head = Some(first),
+            }
+            match next {
+                Some(mut n) => n.as_mut().prev = Some(last),
+                None => self.tail = Some(last),
+            }
+        }
+        self.len += count;
+    }
+
+    //  Tira node da lista e retoma a posse do Box
+    unsafe fn unlink(&mut self, node: NonNull<Node<T>>) -> Box<Node<T>> {
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+
+        unsafe {
+            match node.prev {
+                Some(mut p) => p.as_mut().next = node.next,
+                None => self.head = node.next,
+            }
+            match node.next {
+                Some(mut n) => n.as_mut().prev = node.prev,
+                None => self.tail = node.prev,
+            }
+        }
+        self.len -= 1;
+        return node;
+    }
+}
+
+impl<T> Drop for DoublyLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for DoublyLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for DoublyLinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Clone> Clone for DoublyLinkedList<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        list.extend(iter);
+        return list;
+    }
+}
+
+impl<T> Extend<T> for DoublyLinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Percorre a lista pelas duas pontas; head e tail se encontram no meio
+pub struct Iter<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &*self.head?.as_ptr() };
+        self.head = node.next;
+        self.len -= 1;
+        return Some(&node.value);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &*self.tail?.as_ptr() };
+        self.tail = node.prev;
+        self.len -= 1;
+        return Some(&node.value);
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &mut *self.head?.as_ptr() };
+        self.head = node.next;
+        self.len -= 1;
+        return Some(&mut node.value);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {return None;}
+
+        let node = unsafe { &mut *self.tail?.as_ptr() };
+        self.tail = node.prev;
+        self.len -= 1;
+        return Some(&mut node.value);
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consome a lista, devolvendo os valores
+pub struct IntoIter<T>(DoublyLinkedList<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a DoublyLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut DoublyLinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Cursor que pode alterar a lista na posição em que está <br>
+/// Quando current é None, o cursor está no fantasma, entre o tail e o head
+pub struct CursorMut<'a, T> {
+    current: Option<NonNull<Node<T>>>,
+    //  posição de current; no fantasma é igual a list.len
+    index: usize,
+    list: &'a mut DoublyLinkedList<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Posição do cursor, **None** no fantasma
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    /// Valor na posição do cursor, **None** no fantasma
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Valor seguinte, sem mover o cursor
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = self.next_node()?;
+        return Some(unsafe { &mut (*next.as_ptr()).value });
+    }
+
+    /// Valor anterior, sem mover o cursor
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = self.prev_node()?;
+        return Some(unsafe { &mut (*prev.as_ptr()).value });
+    }
+
+    /// Avança uma posição; do tail vai para o fantasma, e do fantasma para o head
+    pub fn move_next(&mut self) {
+        self.index = match self.current {
+            Some(_) => self.index + 1,
+            None => 0,
+        };
+        self.current = self.next_node();
+    }
+
+    /// Volta uma posição; do head vai para o fantasma, e do fantasma para o tail
+    pub fn move_prev(&mut self) {
+        self.current = self.prev_node();
+        self.index = match self.current {
+            //  do fantasma, index é len e vai para o tail, em len - 1
+            Some(_) => self.index - 1,
+            None => self.list.len,
+        };
+    }
+
+    /// Insere value depois do cursor (no fantasma, no começo da lista)
+    pub fn insert_after(&mut self, value: T) {
+        let node = DoublyLinkedList::new_node(value);
+        let next = self.next_node();
+        unsafe { self.list.link_between(self.current, next, node, node, 1) };
+
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Insere value antes do cursor (no fantasma, no fim da lista)
+    pub fn insert_before(&mut self, value: T) {
+        let node = DoublyLinkedList::new_node(value);
+        let prev = self.prev_node();
+        unsafe { self.list.link_between(prev, self.current, node, node, 1) };
+
+        self.index += 1;
+    }
+
+    /// Remove o valor do cursor, devolvendo-o <br>
+    /// O cursor passa para o valor seguinte
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.current?;
+
+        let node = unsafe { self.list.unlink(node) };
+        self.current = node.next;
+        return Some(node.value);
+    }
+
+    /// Move todos os valores de other para depois do cursor, em O(1)
+    pub fn splice_after(&mut self, other: DoublyLinkedList<T>) {
+        let next = self.next_node();
+        self.splice_between(self.current, next, other);
+
+        if self.current.is_none() {
+            self.index = self.list.len;
+        }
+    }
+
+    /// Move todos os valores de other para antes do cursor, em O(1)
+    pub fn splice_before(&mut self, other: DoublyLinkedList<T>) {
+        let prev = self.prev_node();
+        self.index += other.len;
+        self.splice_between(prev, self.current, other);
+    }
+
+    fn splice_between(&mut self, prev: Option<NonNull<Node<T>>>, next: Option<NonNull<Node<T>>>, mut other: DoublyLinkedList<T>) {
+        let (Some(first), Some(last)) = (other.head.take(), other.tail.take()) else {return;};
+        let count = std::mem::take(&mut other.len);
+
+        //  other ficou vazia e não desaloca nada no seu Drop
+        unsafe { self.list.link_between(prev, next, first, last, count) };
+    }
+
+    fn next_node(&self) -> Option<NonNull<Node<T>>> {
+        match self.current {
+            Some(node) => unsafe { node.as_ref().next },
+            None => self.list.head,
+        }
+    }
+
+    fn prev_node(&self) -> Option<NonNull<Node<T>>> {
+        match self.current {
+            Some(node) => unsafe { node.as_ref().prev },
+            None => self.list.tail,
+        }
+    }
+}
@@ -1,5 +1,23 @@
+#![allow(clippy::needless_return)]
+
 mod linked_list;
+pub use linked_list::LinkedList as BoxedLinkedList;
+#[allow(clippy::module_inception)]
 mod tests;
-// pub use linked_list::LinkedList;
+mod testes_modelo;
+mod testes_serde;
+mod testes_doubly;
+mod testes_circular;
+mod testes_adapters;
 mod linked_list_simple;
-pub use linked_list_simple::LinkedList;
\ No newline at end of file
+pub use linked_list_simple::{IntoIter, Iter, IterMut, LinkedList};
+pub mod doubly_linked_list;
+pub use doubly_linked_list::DoublyLinkedList;
+pub mod circular_list;
+pub use circular_list::CircularList;
+mod list;
+pub use list::List;
+pub mod adapters;
+pub use adapters::{BoundedQueue, BoundedStack, CapacityError, Queue, Stack};
+#[cfg(feature = "serde")]
+mod serde_impls;
@@ -1,10 +1,10 @@
 
-pub struct Node<T: PartialEq> {
+pub struct Node<T> {
     value: T,
     next: Option<Box<Node<T>>>,
 }
 
-impl<T: PartialEq> Node<T> {
+impl<T> Node<T> {
     pub fn new(value: T) -> Node<T> {
         return Node {
             value,
@@ -28,12 +28,12 @@ impl<T: PartialEq> Node<T> {
     }
 }
 
-pub struct LinkedList<T: PartialEq> {
+pub struct LinkedList<T> {
     head: Option<Node<T>>,
     len: usize
 }
 
-impl<T: PartialEq> LinkedList<T> {
+impl<T> LinkedList<T> {
 
     pub fn new() -> LinkedList<T> {
         return LinkedList {
@@ -46,6 +46,10 @@ impl<T: PartialEq> LinkedList<T> {
         return self.len;
     }
 
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
     pub fn push(&mut self, value: T) {
 
         self.len += 1;
@@ -61,22 +65,41 @@ impl<T: PartialEq> LinkedList<T> {
         current_node.add_next(value);
     }
 
-    pub fn remove(&mut self, value: T) {
+    /// Remove o primeiro valor, devolvendo-o
+    pub fn pop_front(&mut self) -> Option<T> {
+        let mut head = self.head.take()?;
+
+        //  o próximo node sai do Box e passa a ser o head
+        self.head = head.next.take().map(|next_node| *next_node);
+        self.len -= 1;
+        return Some(head.value);
+    }
 
-        let Some(head) = self.head.as_mut() else {return;};
+    pub fn list(&self) -> Vec<&T> {
+        let mut vec: Vec<&T> = Vec::with_capacity(self.len);
+
+        let Some(mut current_node) = self.head.as_ref() else {return vec;};
+        vec.push(&current_node.value);
+
+        while let Some(next) = &current_node.next {
+            vec.push(&next.value);
+            current_node = next;
+        }
+
+        return vec;
+    }
+
+}
+
+//  PartialEq só é necessário para procurar um valor
+impl<T: PartialEq> LinkedList<T> {
+    /// Remove a primeira ocorrência de value, devolvendo o valor que estava na lista
+    pub fn remove(&mut self, value: T) -> Option<T> {
+
+        let head = self.head.as_mut()?;
 
         if head.value == value {
-            self.len -= 1;
-            //  take torna head.next = None
-            //  isso não importa, pois head vai ser descartado e 
-            //  seu conteúdo anexado a self.head
-            let Some(next_node) = head.next.take() else {
-                self.head = None;
-                return;
-            };
-            // restaura o valor de self.head
-            self.head = Some(*next_node);
-            return;
+            return self.pop_front();
         }
 
         let mut current_node: &mut Node<T> = head;
@@ -91,30 +114,29 @@ impl<T: PartialEq> LinkedList<T> {
         }
 
         //  loop anterior chegou ao fim da lista e não enconrou o valor, retorna
-        let Some(next_node) = current_node.next() else {return;};
+        let mut next_node = current_node.next.take()?;
 
-        current_node.next = match next_node.next.take() {
-            Some(node) => Some(node),
-            None => None,
-        };
+        current_node.next = next_node.next.take();
 
         self.len -= 1;
-
+        return Some(next_node.value);
     }
 
-    pub fn list(&self) -> Vec<&T> {
-        dbg!("len: {}", self.len);
-        let mut vec: Vec<&T> = Vec::with_capacity(self.len);
-
-        let Some(mut current_node) = self.head.as_ref() else {return vec;};
-        vec.push(&current_node.value);
-
-        while let Some(next) = &current_node.next {
-            vec.push(&next.value);
-            current_node = &next;
-        }
+    pub fn contains(&self, value: &T) -> bool {
+        return self.list().into_iter().any(|v| v == value);
+    }
+}
 
-        return vec;
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList::new()
     }
+}
 
+//  O Drop gerado pelo compilador desalocaria os Box recursivamente,
+//  um nível de pilha por node; aqui eles são desalocados um por vez
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
 }
\ No newline at end of file
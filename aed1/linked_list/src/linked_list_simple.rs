@@ -1,4 +1,4 @@
-use std::ptr::NonNull;
+use std::{marker::PhantomData, ptr::NonNull};
 
 //  Um node simples
 //  NonNull diz para o compilador que o ponteiro nunca 
@@ -16,33 +16,30 @@ impl<T> Node<T> {
             next: None,
         };
     }
-
-    pub fn next(&self) -> Option<NonNull<Node<T>>> {
-        return self.next;
-    }
-
-    pub fn add_next(&mut self, value: T) {
-        let next_node = Node {
-            value,
-            next: None,
-        };
-        let leaked = Box::leak(Box::new(next_node));
-        let non_null = NonNull::from_ref(&*leaked);
-        self.next = Some(non_null);
-    }
 }
 
-pub struct LinkedList<T: PartialEq> {
+//  Ponteiro para um node, ou o fim da sequência
+type Link<T> = Option<NonNull<Node<T>>>;
+
+//  tail aponta para o último node, para que push_back não
+//  precise percorrer a lista
+pub struct LinkedList<T> {
     head: Option<NonNull<Node<T>>>,
+    tail: Option<NonNull<Node<T>>>,
     len: usize
 }
 
-//  PatialEq pode ser qualquer tipo de dado
-//  que possa ser comparado com "=="
-impl<T: PartialEq> LinkedList<T> {
+impl<T> Default for LinkedList<T> {
+    fn default() -> Self {
+        LinkedList::new()
+    }
+}
+
+impl<T> LinkedList<T> {
     pub fn new() -> LinkedList<T> {
         LinkedList {
             head: None,
+            tail: None,
             len: 0,
         }
     }
@@ -51,81 +48,432 @@ impl<T: PartialEq> LinkedList<T> {
         self.len
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// O mesmo que push_back()
     pub fn push(&mut self, value: T) {
-        
-        if self.len == 0 {
-            let node = Node::new(value);
-            let leaked = Box::leak(Box::new(node));
-            let non_null_node = NonNull::from_ref(&*leaked);
-
-            self.head = Some(non_null_node);
-            self.len += 1;
-            return;
-        };
+        self.push_back(value);
+    }
 
-        let current_node = self.head.unwrap();  //  garantido que existe, visto que len > 0
-        let mut current_node: &mut Node<T> = unsafe {&mut *current_node.as_ptr()};  
+    /// Adiciona value no fim da lista, em O(1)
+    pub fn push_back(&mut self, value: T) {
+        let leaked = Box::leak(Box::new(Node::new(value)));
+        let non_null_node = NonNull::from_mut(leaked);
 
-        while let Some(next_node) = current_node.next() {
-            current_node = unsafe {&mut *next_node.as_ptr()};
+        match self.tail {
+            Some(mut tail) => unsafe { tail.as_mut().next = Some(non_null_node) },
+            None => self.head = Some(non_null_node),
         }
 
-        current_node.add_next(value);
+        self.tail = Some(non_null_node);
         self.len += 1;
     }
 
-    pub fn remove(&mut self, value: T) {
-        if self.len == 0 {return;}
+    /// Adiciona value no começo da lista, em O(1)
+    pub fn push_front(&mut self, value: T) {
+        let node = Node { value, next: self.head };
+        let leaked = Box::leak(Box::new(node));
+        let non_null_node = NonNull::from_mut(leaked);
 
-        let current_node = unsafe {self.head.unwrap().as_ref()};  //  garantido que existe, visto que len > 0
-        if current_node.value == value {
-            self.head = None;
-            self.len -= 1;
-            drop(Box::new(current_node));   //  desaloca current node
-            return
+        if self.tail.is_none() {
+            self.tail = Some(non_null_node);
         }
 
-        let mut past_node = unsafe {self.head.unwrap().as_mut()};
-        let mut current_node = unsafe { 
-            let Some(n) = current_node.next else {return;};
-            n.as_ref()
+        self.head = Some(non_null_node);
+        self.len += 1;
+    }
+
+    /// Remove o primeiro valor, devolvendo-o, em O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head?;
+        return Some(unsafe { self.unlink_after(None) });
+    }
+
+    /// Remove o último valor, devolvendo-o <br>
+    /// É O(n): como a lista é simplesmente encadeada, é preciso
+    /// percorrê-la para achar o penúltimo node, que vira o novo tail
+    pub fn pop_back(&mut self) -> Option<T> {
+        return self.remove_at(self.len.checked_sub(1)?);
+    }
+
+    /// Valor na posição i, em O(i) (o último é O(1))
+    pub fn get(&self, i: usize) -> Option<&T> {
+        let node = self.node_at(i)?;
+        return Some(unsafe { &(*node.as_ptr()).value });
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let node = self.node_at(i)?;
+        return Some(unsafe { &mut (*node.as_ptr()).value });
+    }
+
+    /// Insere value na posição i, deslocando os seguintes <br>
+    /// i == len() insere no fim
+    ///
+    /// # Panics
+    /// Se i > len()
+    pub fn insert(&mut self, i: usize, value: T) {
+        assert!(i <= self.len, "índice {} fora da lista de tamanho {}", i, self.len);
+
+        if i == 0 {
+            return self.push_front(value);
+        }
+        if i == self.len {
+            return self.push_back(value);
+        }
+
+        let mut past_node = self.node_at(i - 1).unwrap();  //  garantido que existe, visto que i < len
+        let node = Node { value, next: unsafe { past_node.as_ref().next } };
+        let leaked = Box::leak(Box::new(node));
+
+        unsafe { past_node.as_mut().next = Some(NonNull::from_mut(leaked)) };
+        self.len += 1;
+    }
+
+    /// Remove o valor na posição i, devolvendo-o <br>
+    /// **None** se i >= len()
+    pub fn remove_at(&mut self, i: usize) -> Option<T> {
+        if i >= self.len {return None;}
+
+        let past_node = match i {
+            0 => None,
+            _ => self.node_at(i - 1),
         };
+        return Some(unsafe { self.unlink_after(past_node) });
+    }
 
-        while current_node.value != value {
-            if current_node.next.is_none() {return;}
+    /// Mantém apenas os valores para os quais f devolve true, na mesma ordem
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut past_node: Link<T> = None;
+        let mut current_node = self.head;
 
-            past_node = current_node;
-            current_node = unsafe { past_node.next.unwrap().as_ref() };
+        while let Some(node) = current_node {
+            current_node = unsafe { node.as_ref().next };
+
+            if f(unsafe { &node.as_ref().value }) {
+                past_node = Some(node);
+            } else {
+                unsafe { self.unlink_after(past_node) };
+            }
+        }
+    }
+
+    /// Primeiro valor para o qual f devolve true
+    pub fn find<F: FnMut(&T) -> bool>(&self, mut f: F) -> Option<&T> {
+        self.iter().find(|v| f(v))
+    }
+
+    /// Posição do primeiro valor para o qual f devolve true
+    pub fn position<F: FnMut(&T) -> bool>(&self, f: F) -> Option<usize> {
+        self.iter().position(f)
+    }
+
+    //  Node na posição i, ou None se i >= len
+    fn node_at(&self, i: usize) -> Link<T> {
+        if i >= self.len {return None;}
+        if i == self.len - 1 {return self.tail;}
+
+        let mut node = self.head?;
+        for _ in 0..i {
+            node = unsafe { node.as_ref().next? };
         }
+        return Some(node);
+    }
 
-        //  nesse ponto o valor está no current_node 
-        if let Some(next_node) = current_node.next {
-            past_node.next = Some(next_node);
-        } 
-        else {
-            past_node.next = None;
+    //  Tira da lista o node seguinte a past_node (o head, se past_node é None),
+    //  que precisa existir, e devolve o seu valor
+    unsafe fn unlink_after(&mut self, past_node: Link<T>) -> T {
+        let next = match past_node {
+            Some(p) => unsafe { p.as_ref().next },
+            None => self.head,
+        };
+        //  retoma a posse do Box vazado em push, que é desalocado aqui
+        let node = unsafe { Box::from_raw(next.unwrap().as_ptr()) };
+
+        match past_node {
+            Some(mut p) => unsafe { p.as_mut().next = node.next },
+            None => self.head = node.next,
+        }
+        if node.next.is_none() {
+            self.tail = past_node;
         }
+
         self.len -= 1;
-        drop(Box::new(current_node));   //  desaloca current node
+        return node.value;
     }
 
-
+    /// Referências para os valores, em ordem
     pub fn list(&self) -> Vec<&T> {
-        dbg!("len: {}", self.len);
-        let mut vec: Vec<&T> = Vec::with_capacity(self.len);
+        return self.iter().collect();
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head, len: self.len, marker: PhantomData }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head, len: self.len, marker: PhantomData }
+    }
+
+    /// Inverte a ordem da lista no lugar, como o inverte() de linked_lisk_invert.cpp <br>
+    /// Só os ponteiros next mudam; nenhum node é alocado
+    pub fn reverse(&mut self) {
+        let mut past_node: Link<T> = None;
+        let mut current_node = self.head;
+
+        while let Some(mut node) = current_node {
+            unsafe {
+                current_node = node.as_ref().next;
+                node.as_mut().next = past_node;
+            }
+            past_node = Some(node);
+        }
+
+        self.tail = self.head;
+        self.head = past_node;
+    }
+}
+
+//  Só os métodos que procuram por um valor precisam de PartialEq,
+//  que pode ser qualquer tipo de dado que possa ser comparado com "=="
+impl<T: PartialEq> LinkedList<T> {
+    /// Remove a primeira ocorrência de value, devolvendo o valor que estava na lista <br>
+    /// **None** se value não foi encontrado
+    pub fn remove(&mut self, value: T) -> Option<T> {
+        let mut past_node: Link<T> = None;
+        let mut current_node = self.head;
+
+        while let Some(node) = current_node {
+            if unsafe { node.as_ref().value == value } {
+                return Some(unsafe { self.unlink_after(past_node) });
+            }
+            past_node = current_node;
+            current_node = unsafe { node.as_ref().next };
+        }
+
+        return None;
+    }
+
+    /// Remove todas as ocorrências de value, devolvendo quantas foram removidas
+    pub fn remove_all(&mut self, value: &T) -> usize {
+        let len = self.len;
+        self.retain(|v| v != value);
+        return len - self.len;
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        self.iter().any(|v| v == value)
+    }
+}
+
+//  merge e sort precisam comparar com "<", e não só com "=="
+impl<T: PartialOrd> LinkedList<T> {
+    /// Junta other a esta lista, que devem estar ordenadas, mantendo a ordem <br>
+    /// Os nodes das duas listas são religados, sem alocar nenhum novo;
+    /// entre valores iguais, os desta lista vêm antes dos de other
+    pub fn merge_sorted(&mut self, mut other: LinkedList<T>) {
+        //  other fica vazia, então seu Drop não desaloca os nodes
+        let other_head = other.head.take();
+        other.tail = None;
+        let other_len = std::mem::take(&mut other.len);
+
+        let (head, tail) = unsafe { merge_nodes(self.head, other_head) };
+        self.head = head;
+        self.tail = tail;
+        self.len += other_len;
+    }
 
-        let Some(current_node) = self.head.as_ref() else {return vec;};
-        let mut current_node: &Node<T> = unsafe {current_node.as_ref()}; 
+    /// Ordena a lista com merge sort sobre os nodes, em O(n log n) <br>
+    /// A ordenação é estável: valores iguais mantêm a ordem em que estavam
+    pub fn sort(&mut self) {
+        let (head, tail) = unsafe { sort_nodes(self.head, self.len) };
+        self.head = head;
+        self.tail = tail;
+    }
+}
+
+//  Intercala duas sequências ordenadas de nodes, terminadas em None,
+//  devolvendo o primeiro e o último node da sequência resultante.
+//  Em caso de empate, o node de a vem primeiro, o que mantém o sort estável
+unsafe fn merge_nodes<T: PartialOrd>(mut a: Link<T>, mut b: Link<T>) -> (Link<T>, Link<T>) {
+    let mut head: Link<T> = None;
+    let mut tail: Link<T> = None;
 
-        vec.push(&current_node.value);
+    loop {
+        let next = match (a, b) {
+            (Some(x), Some(y)) => unsafe {
+                if y.as_ref().value < x.as_ref().value {
+                    b = y.as_ref().next;
+                    y
+                } else {
+                    a = x.as_ref().next;
+                    x
+                }
+            },
+            //  o que sobrou de uma das sequências já está ordenado e termina em None
+            (rest, None) | (None, rest) => {
+                let Some(rest) = rest else {return (head, tail);};
+
+                match tail {
+                    Some(mut t) => unsafe { t.as_mut().next = Some(rest) },
+                    None => head = Some(rest),
+                }
+                let mut last = rest;
+                while let Some(next) = unsafe { last.as_ref().next } {
+                    last = next;
+                }
+                return (head, Some(last));
+            }
+        };
 
-        for _ in 1..self.len {
-            current_node = unsafe { current_node.next().unwrap().as_ref() };
-            vec.push(&current_node.value);
+        match tail {
+            Some(mut t) => unsafe { t.as_mut().next = Some(next) },
+            None => head = Some(next),
         }
+        tail = Some(next);
+    }
+}
+
+//  Merge sort dos len primeiros nodes a partir de head: divide a
+//  sequência ao meio, ordena cada metade e as intercala.
+//  A recursão tem profundidade log2(len)
+unsafe fn sort_nodes<T: PartialOrd>(head: Link<T>, len: usize) -> (Link<T>, Link<T>) {
+    if len <= 1 {
+        return (head, head);
+    }
+
+    //  o último node da primeira metade passa a terminar em None
+    let mut middle = head.unwrap();  //  garantido que existe, visto que len > 1
+    for _ in 1..len / 2 {
+        middle = unsafe { middle.as_ref().next.unwrap() };
+    }
+    let second = unsafe { middle.as_mut().next.take() };
+
+    let (first, _) = unsafe { sort_nodes(head, len / 2) };
+    let (second, _) = unsafe { sort_nodes(second, len - len / 2) };
+    return unsafe { merge_nodes(first, second) };
+}
+
+//  Os nodes não são donos do próximo (next é um ponteiro cru), então
+//  a lista precisa desalocá-los; um por vez, para que listas longas
+//  não estourem a pilha como um Drop recursivo faria
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut ll = LinkedList::new();
+        ll.extend(iter);
+        return ll;
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+/// Percorre a lista do head ao tail
+pub struct Iter<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
 
-        return vec;
+    fn next(&mut self) -> Option<&'a T> {
+        let node = unsafe { &*self.next?.as_ptr() };
+        self.next = node.next;
+        self.len -= 1;
+        return Some(&node.value);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<NonNull<Node<T>>>,
+    len: usize,
+    marker: PhantomData<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        let node = unsafe { &mut *self.next?.as_ptr() };
+        self.next = node.next;
+        self.len -= 1;
+        return Some(&mut node.value);
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Consome a lista, devolvendo os valores do head ao tail
+pub struct IntoIter<T>(LinkedList<T>);
 
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
 }
@@ -0,0 +1,186 @@
+/*
+ *  O que todas as listas do crate têm em comum, para que código
+ *  (e os testes em tests.rs) possa ser escrito uma vez só e rodar
+ *  com qualquer uma delas:
+ *      - LinkedList: simplesmente encadeada, com ponteiros crus (linked_list_simple.rs)
+ *      - BoxedLinkedList: simplesmente encadeada, com Box (linked_list.rs)
+ *      - DoublyLinkedList e CircularList
+ *
+ *  Os métodos com o mesmo nome nos tipos continuam disponíveis sem
+ *  importar o trait; ele só é necessário em código genérico.
+*/
+
+use crate::{
+    circular_list::CircularList, doubly_linked_list::DoublyLinkedList, linked_list::LinkedList as BoxedLinkedList,
+    linked_list_simple::LinkedList,
+};
+
+pub trait List<T>: Default {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adiciona value no fim da lista
+    fn push(&mut self, value: T);
+
+    /// Remove o primeiro valor, devolvendo-o
+    fn pop_front(&mut self) -> Option<T>;
+
+    /// Remove a primeira ocorrência de value, devolvendo o valor que estava na lista
+    fn remove(&mut self, value: T) -> Option<T>
+    where
+        T: PartialEq;
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq;
+
+    /// Referências para os valores, em ordem
+    fn list(&self) -> Vec<&T>;
+}
+
+impl<T> List<T> for LinkedList<T> {
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn remove(&mut self, value: T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        LinkedList::remove(self, value)
+    }
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        LinkedList::contains(self, value)
+    }
+
+    fn list(&self) -> Vec<&T> {
+        LinkedList::list(self)
+    }
+}
+
+impl<T> List<T> for BoxedLinkedList<T> {
+    fn len(&self) -> usize {
+        BoxedLinkedList::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        BoxedLinkedList::push(self, value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        BoxedLinkedList::pop_front(self)
+    }
+
+    fn remove(&mut self, value: T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        BoxedLinkedList::remove(self, value)
+    }
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        BoxedLinkedList::contains(self, value)
+    }
+
+    fn list(&self) -> Vec<&T> {
+        BoxedLinkedList::list(self)
+    }
+}
+
+impl<T> List<T> for DoublyLinkedList<T> {
+    fn len(&self) -> usize {
+        DoublyLinkedList::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        DoublyLinkedList::pop_front(self)
+    }
+
+    fn remove(&mut self, value: T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let mut cursor = self.cursor_front_mut();
+        while let Some(current) = cursor.current() {
+            if *current == value {
+                return cursor.remove_current();
+            }
+            cursor.move_next();
+        }
+        return None;
+    }
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    fn list(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+impl<T> List<T> for CircularList<T> {
+    fn len(&self) -> usize {
+        CircularList::len(self)
+    }
+
+    fn push(&mut self, value: T) {
+        self.push_back(value);
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        CircularList::pop_front(self)
+    }
+
+    fn remove(&mut self, value: T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        //  o cursor dá voltas na lista, então o número de passos é limitado por len
+        let len = CircularList::len(self);
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..len {
+            if cursor.current().is_some_and(|current| *current == value) {
+                return cursor.remove_current();
+            }
+            cursor.move_next();
+        }
+        return None;
+    }
+
+    fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|v| v == value)
+    }
+
+    fn list(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
@@ -0,0 +1,41 @@
+//  Serialize/Deserialize para LinkedList (feature "serde")
+//  A lista é salva como uma sequência, na mesma ordem
+
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::linked_list_simple::LinkedList;
+
+impl<T: Serialize> Serialize for LinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for LinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(LinkedListVisitor(PhantomData))
+    }
+}
+
+struct LinkedListVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for LinkedListVisitor<T> {
+    type Value = LinkedList<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("uma sequência de valores")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<LinkedList<T>, A::Error> {
+        let mut ll = LinkedList::new();
+        while let Some(value) = seq.next_element()? {
+            ll.push_back(value);
+        }
+        return Ok(ll);
+    }
+}
@@ -0,0 +1,161 @@
+//  Testes de Stack, Queue e das versões com capacidade
+
+#[cfg(test)]
+mod tests {
+    use crate::adapters::{BoundedQueue, BoundedStack, CapacityError, Queue, Stack};
+
+    #[test]
+    fn stack_is_lifo() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn stack_peek_mut() {
+        let mut stack: Stack<i32> = (1..=2).collect();
+        *stack.peek_mut().unwrap() += 10;
+
+        assert_eq!(stack.pop(), Some(12));
+        assert_eq!(stack.peek(), Some(&1));
+    }
+
+    #[test]
+    fn stack_iteration() {
+        let mut stack: Stack<i32> = (1..=3).collect();
+        stack.extend([4, 5]);
+
+        //  do topo para a base, como pop() devolveria
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+        let total: i32 = (&stack).into_iter().sum();
+        assert_eq!(total, 15);
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn iterator_types_are_public() {
+        let stack: BoundedStack<i32> = BoundedStack::new(2);
+        let queue: Queue<i32> = (1..=2).collect();
+
+        let iter: crate::Iter<'_, i32> = stack.iter();
+        let owned: crate::IntoIter<i32> = queue.into_iter();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(owned.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn queue_is_fifo() {
+        let mut queue = Queue::new();
+        queue.push(1);
+        queue.push(2);
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(3);
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.peek(), Some(&2));
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), None);
+        assert!(queue.is_empty());
+
+        //  depois de esvaziar, o tail precisa ter sido esquecido
+        queue.push(4);
+        assert_eq!(queue.peek(), Some(&4));
+    }
+
+    #[test]
+    fn queue_peek_mut_and_iteration() {
+        let mut queue: Queue<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        queue.extend(["c".to_string()]);
+        queue.peek_mut().unwrap().push('!');
+
+        assert_eq!(queue.iter().map(String::as_str).collect::<Vec<_>>(), vec!["a!", "b", "c"]);
+        let mut lens = Vec::new();
+        for s in &queue {
+            lens.push(s.len());
+        }
+        assert_eq!(lens, vec![2, 1, 1]);
+        assert_eq!(queue.into_iter().last(), Some("c".to_string()));
+    }
+
+    #[test]
+    fn clear() {
+        let mut stack: Stack<i32> = (1..=3).collect();
+        let mut queue: Queue<i32> = (1..=3).collect();
+        stack.clear();
+        queue.clear();
+
+        assert!(stack.is_empty() && queue.is_empty());
+        queue.push(1);
+        assert_eq!(queue.pop(), Some(1));
+    }
+
+    #[test]
+    fn bounded_stack() {
+        let mut stack = BoundedStack::new(2);
+        assert_eq!(stack.capacity(), 2);
+        assert_eq!(stack.push(1), Ok(()));
+        assert_eq!(stack.push(2), Ok(()));
+        assert!(stack.is_full());
+
+        let err = stack.push(3).unwrap_err();
+        assert_eq!(err, CapacityError { value: 3, capacity: 2 });
+        assert_eq!(err.to_string(), "capacidade de 2 valores atingida");
+        assert_eq!(err.into_inner(), 3);
+        assert_eq!(stack.len(), 2);
+
+        //  tirar um valor abre espaço de novo
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.push(4), Ok(()));
+        *stack.peek_mut().unwrap() *= 10;
+        assert_eq!(stack.peek(), Some(&40));
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![40, 1]);
+        assert_eq!(stack.into_iter().collect::<Vec<_>>(), vec![40, 1]);
+    }
+
+    #[test]
+    fn bounded_queue() {
+        let mut queue = BoundedQueue::new(3);
+        for i in 1..=3 {
+            queue.push(i).unwrap();
+        }
+        assert!(queue.is_full());
+        assert_eq!(queue.push(4).map_err(CapacityError::into_inner), Err(4));
+
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(5).unwrap();
+        assert_eq!(queue.peek(), Some(&2));
+        assert_eq!((&queue).into_iter().copied().collect::<Vec<_>>(), vec![2, 3, 5]);
+
+        queue.clear();
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut stack: BoundedStack<i32> = BoundedStack::new(0);
+        let mut queue: BoundedQueue<i32> = BoundedQueue::new(0);
+
+        assert!(stack.is_full() && queue.is_full());
+        assert!(stack.push(1).is_err());
+        assert!(queue.push(1).is_err());
+    }
+
+    #[test]
+    fn capacity_error_is_an_error() {
+        let err: Box<dyn std::error::Error> = Box::new(CapacityError { value: "x", capacity: 1 });
+        assert_eq!(err.to_string(), "capacidade de 1 valores atingida");
+        assert!(err.source().is_none());
+    }
+}
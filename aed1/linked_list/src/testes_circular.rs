@@ -0,0 +1,233 @@
+//  Testes da CircularList
+//  Assim como testes_modelo, rodam sob o Miri:
+//      cargo +nightly miri test testes_circular
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::circular_list::CircularList;
+
+    fn values<T: Clone>(list: &CircularList<T>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+
+    #[test]
+    fn new() {
+        let list: CircularList<i32> = CircularList::new();
+
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let mut list = CircularList::new();
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+
+        assert_eq!(values(&list), vec![1, 2, 3]);
+        assert_eq!((list.front(), list.back()), (Some(&1), Some(&3)));
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.is_empty());
+
+        list.push_front(4);
+        assert_eq!((list.front(), list.back()), (Some(&4), Some(&4)));
+    }
+
+    #[test]
+    fn iteration_visits_each_value_once() {
+        let mut list: CircularList<i32> = (1..=4).collect();
+
+        assert_eq!(list.iter().count(), 4);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        assert_eq!(list.iter().len(), 4);
+
+        for value in &mut list {
+            *value *= 10;
+        }
+        assert_eq!(format!("{:?}", list), "[10, 20, 30, 40]");
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.next_back(), Some(40));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn rotate() {
+        let mut list: CircularList<i32> = (1..=5).collect();
+
+        list.rotate_left(2);
+        assert_eq!(values(&list), vec![3, 4, 5, 1, 2]);
+        list.rotate_right(2);
+        assert_eq!(values(&list), vec![1, 2, 3, 4, 5]);
+
+        //  pelo lado mais curto, e dando mais de uma volta
+        list.rotate_left(4);
+        assert_eq!(values(&list), vec![5, 1, 2, 3, 4]);
+        list.rotate_right(11);
+        assert_eq!(values(&list), vec![4, 5, 1, 2, 3]);
+        list.rotate_left(5);
+        assert_eq!(values(&list), vec![4, 5, 1, 2, 3]);
+
+        let mut empty: CircularList<i32> = CircularList::new();
+        empty.rotate_left(3);
+        empty.rotate_right(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn rotate_one() {
+        let mut list: CircularList<i32> = (1..=3).collect();
+
+        list.rotate_left_one();
+        assert_eq!(values(&list), vec![2, 3, 1]);
+        list.rotate_right_one();
+        list.rotate_right_one();
+        assert_eq!(values(&list), vec![3, 1, 2]);
+        assert_eq!((list.front(), list.back()), (Some(&3), Some(&2)));
+
+        let mut single: CircularList<i32> = (1..=1).collect();
+        single.rotate_left_one();
+        assert_eq!(values(&single), vec![1]);
+
+        let mut empty: CircularList<i32> = CircularList::new();
+        empty.rotate_left_one();
+        empty.rotate_right_one();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn rotate_matches_vecdeque() {
+        let mut list: CircularList<usize> = (0..7).collect();
+        let mut model: VecDeque<usize> = (0..7).collect();
+
+        for n in 0..20 {
+            list.rotate_left(n);
+            model.rotate_left(n % 7);
+            assert!(list.iter().eq(model.iter()));
+
+            list.rotate_right(n * 3);
+            model.rotate_right(n * 3 % 7);
+            assert!(list.iter().eq(model.iter()));
+        }
+    }
+
+    #[test]
+    fn cursor_wraps_around() {
+        let list: CircularList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_front();
+
+        assert_eq!(cursor.current(), Some(&1));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&3));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+
+        //  como iterador, o cursor não termina
+        let voltas: Vec<i32> = list.cursor_front().take(7).copied().collect();
+        assert_eq!(voltas, vec![1, 2, 3, 1, 2, 3, 1]);
+
+        let empty: CircularList<i32> = CircularList::new();
+        let mut cursor = empty.cursor_front();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        assert_eq!(empty.cursor_front().next(), None);
+    }
+
+    #[test]
+    fn cursor_mut_edits() {
+        let mut list: CircularList<i32> = vec![1, 3].into_iter().collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(2);
+        //  antes do head é o fim da lista
+        cursor.insert_before(4);
+        *cursor.current().unwrap() = 0;
+
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.move_prev();
+        cursor.make_head();
+
+        assert_eq!(values(&list), vec![3, 0, 2]);
+    }
+
+    #[test]
+    fn cursor_mut_on_empty_list() {
+        let mut list: CircularList<i32> = CircularList::new();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(1);
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_before(2);
+
+        assert_eq!(values(&list), vec![2]);
+    }
+
+    #[test]
+    fn josephus() {
+        //  7 pessoas, eliminando de 3 em 3: sobra a 4
+        let mut list: CircularList<i32> = (1..=7).collect();
+        let removed = list.remove_every_kth(3);
+
+        assert_eq!(removed, vec![3, 6, 2, 7, 5, 1]);
+        assert_eq!(values(&list), vec![4]);
+    }
+
+    #[test]
+    fn josephus_edge_cases() {
+        let mut list: CircularList<i32> = (1..=4).collect();
+        assert_eq!(list.remove_every_kth(1), vec![1, 2, 3]);
+        assert_eq!(values(&list), vec![4]);
+        assert!(list.remove_every_kth(5).is_empty());
+
+        let mut empty: CircularList<i32> = CircularList::new();
+        assert!(empty.remove_every_kth(2).is_empty());
+
+        //  k maior que a lista dá mais de uma volta
+        let mut list: CircularList<i32> = (1..=3).collect();
+        assert_eq!(list.remove_every_kth(5), vec![2, 3]);
+        assert_eq!(list.front(), Some(&1));
+    }
+
+    #[test]
+    #[should_panic(expected = "k precisa ser maior que 0")]
+    fn josephus_zero() {
+        let mut list: CircularList<i32> = (1..=3).collect();
+        list.remove_every_kth(0);
+    }
+
+    #[test]
+    fn josephus_matches_formula() {
+        //  J(n, k) = (J(n - 1, k) + k) % n, com J(1, k) = 0
+        for n in 1..=if cfg!(miri) { 12 } else { 60 } {
+            for k in 1..=5 {
+                let survivor = (2..=n).fold(0, |j, m| (j + k) % m);
+
+                let mut list: CircularList<usize> = (0..n).collect();
+                let removed = list.remove_every_kth(k);
+                assert_eq!(removed.len(), n - 1);
+                assert_eq!(list.front(), Some(&survivor), "n {} k {}", n, k);
+            }
+        }
+    }
+
+    #[test]
+    fn drop_long_list() {
+        let list: CircularList<u32> = (0..if cfg!(miri) { 1_000 } else { 200_000 }).collect();
+        drop(list);
+    }
+}
@@ -0,0 +1,297 @@
+//  Testes da DoublyLinkedList e do seu CursorMut
+//  Assim como testes_modelo, rodam sob o Miri:
+//      cargo +nightly miri test testes_doubly
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::doubly_linked_list::DoublyLinkedList;
+
+    fn values<T: Clone>(list: &DoublyLinkedList<T>) -> Vec<T> {
+        list.iter().cloned().collect()
+    }
+
+    #[test]
+    fn new() {
+        let list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn push_both_ends() {
+        let mut list = DoublyLinkedList::new();
+        list.push_back(2);
+        list.push_back(3);
+        list.push_front(1);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(values(&list), vec![1, 2, 3]);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn pop_both_ends() {
+        let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+
+        //  head e tail voltam a None, então a lista pode ser reusada
+        list.push_front(5);
+        assert_eq!(list.front(), list.back());
+    }
+
+    #[test]
+    fn front_and_back_mut() {
+        let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+        *list.front_mut().unwrap() *= 10;
+        *list.back_mut().unwrap() *= 10;
+
+        assert_eq!(values(&list), vec![10, 2, 30]);
+    }
+
+    #[test]
+    fn iterators_are_double_ended() {
+        let mut list: DoublyLinkedList<i32> = (1..=5).collect();
+
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), vec![5, 4, 3, 2, 1]);
+
+        //  as duas pontas se encontram sem repetir valores
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 5);
+        assert_eq!((iter.next(), iter.next_back()), (Some(&1), Some(&5)));
+        assert_eq!((iter.next(), iter.next_back()), (Some(&2), Some(&4)));
+        assert_eq!((iter.next(), iter.next_back()), (Some(&3), None));
+        assert_eq!(iter.next(), None);
+
+        for value in list.iter_mut().rev().take(2) {
+            *value = 0;
+        }
+        assert_eq!(values(&list), vec![1, 2, 3, 0, 0]);
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.next_back(), Some(0));
+        assert_eq!(owned.next(), Some(1));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![2, 3, 0]);
+    }
+
+    #[test]
+    fn append() {
+        let mut list: DoublyLinkedList<i32> = (1..=2).collect();
+        let mut other: DoublyLinkedList<i32> = (3..=4).collect();
+
+        list.append(&mut other);
+        assert_eq!(values(&list), vec![1, 2, 3, 4]);
+        assert_eq!(list.len(), 4);
+        assert!(other.is_empty());
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.back(), Some(&4));
+    }
+
+    #[test]
+    fn cursor_moves_through_ghost() {
+        let mut list: DoublyLinkedList<i32> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(0), Some(1)));
+        assert_eq!(cursor.peek_prev(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 2));
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(2), Some(3)));
+
+        //  depois do tail vem o fantasma, e depois o head de novo
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 3));
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(2), Some(3)));
+    }
+
+    #[test]
+    fn cursor_insert() {
+        let mut list: DoublyLinkedList<i32> = vec![2, 4].into_iter().collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_before(1);
+        cursor.insert_after(3);
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(1), Some(2)));
+
+        cursor.move_next();
+        cursor.move_next();
+        cursor.insert_after(5);
+        assert_eq!(values(&list), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.back(), Some(&5));
+    }
+
+    #[test]
+    fn cursor_insert_at_ghost() {
+        let mut list: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        let mut cursor = list.cursor_front_mut();
+
+        //  no fantasma, after é o começo da lista e before é o fim
+        cursor.insert_after(2);
+        cursor.insert_before(3);
+        cursor.insert_after(1);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(2), Some(3)));
+
+        assert_eq!(values(&list), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cursor_remove() {
+        let mut list: DoublyLinkedList<i32> = (1..=4).collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(1), Some(3)));
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(1));
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.remove_current(), None);
+
+        assert!(list.is_empty());
+        assert_eq!((list.front(), list.back()), (None, None));
+    }
+
+    #[test]
+    fn cursor_splice() {
+        let mut list: DoublyLinkedList<i32> = vec![1, 4, 7].into_iter().collect();
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.move_next();
+        cursor.splice_before((2..=3).collect());
+        cursor.splice_after((5..=6).collect());
+        assert_eq!((cursor.index(), cursor.current().copied()), (Some(3), Some(4)));
+
+        cursor.splice_after(DoublyLinkedList::new());
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.splice_before((8..=9).collect());
+        cursor.splice_after((-1..=0).collect());
+
+        assert_eq!(values(&list), vec![-1, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(list.len(), 11);
+        assert_eq!(list.iter().rev().count(), 11);
+    }
+
+    #[test]
+    fn clone_and_eq() {
+        let list: DoublyLinkedList<String> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+        let mut copy = list.clone();
+
+        assert_eq!(list, copy);
+        copy.pop_back();
+        assert_ne!(list, copy);
+        assert_eq!(format!("{:?}", list), "[\"a\", \"b\"]");
+    }
+
+    #[test]
+    fn drop_long_list() {
+        let list: DoublyLinkedList<u32> = (0..if cfg!(miri) { 1_000 } else { 200_000 }).collect();
+        drop(list);
+    }
+
+    //  Sequência aleatória de operações do cursor comparada com um VecDeque,
+    //  onde o cursor é um índice (len é o fantasma)
+    #[test]
+    fn random_cursor_operations_match_vecdeque() {
+        let sequencias = if cfg!(miri) { 4 } else { 200 };
+        let operacoes = if cfg!(miri) { 60 } else { 300 };
+
+        for seed in 0..sequencias {
+            let mut rng: u64 = (seed as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1;
+            let mut next = move || {
+                rng ^= rng << 13;
+                rng ^= rng >> 7;
+                rng ^= rng << 17;
+                rng
+            };
+
+            let mut list = DoublyLinkedList::new();
+            let mut model: VecDeque<u64> = VecDeque::new();
+            let mut cursor = list.cursor_front_mut();
+            let mut pos = 0;
+
+            for step in 0..operacoes {
+                let value = next() % 100;
+
+                match next() % 7 {
+                    0 => {
+                        cursor.move_next();
+                        pos = if pos == model.len() { 0 } else { pos + 1 };
+                    }
+                    1 => {
+                        cursor.move_prev();
+                        pos = if pos == 0 { model.len() } else { pos - 1 };
+                    }
+                    2 => {
+                        cursor.insert_before(value);
+                        model.insert(pos, value);
+                        pos += 1;
+                    }
+                    3 => {
+                        cursor.insert_after(value);
+                        if pos == model.len() {
+                            model.push_front(value);
+                            pos += 1;
+                        } else {
+                            model.insert(pos + 1, value);
+                        }
+                    }
+                    4 | 5 => {
+                        let removed = if pos < model.len() { model.remove(pos) } else { None };
+                        assert_eq!(cursor.remove_current(), removed, "seed {} passo {}", seed, step);
+                    }
+                    _ => {
+                        let other: DoublyLinkedList<u64> = (value..value + next() % 3).collect();
+                        for (i, v) in other.iter().enumerate() {
+                            model.insert(pos + i, *v);
+                        }
+                        pos += other.len();
+                        cursor.splice_before(other);
+                    }
+                }
+
+                let index = if pos == model.len() { None } else { Some(pos) };
+                assert_eq!(cursor.index(), index, "seed {} passo {}", seed, step);
+                assert_eq!(cursor.current().copied(), model.get(pos).copied(), "seed {} passo {}", seed, step);
+            }
+
+            //  o cursor não é mais usado, então list pode ser lida de novo
+            assert_eq!(list.len(), model.len());
+            assert!(list.iter().eq(model.iter()));
+            assert!(list.iter().rev().eq(model.iter().rev()));
+        }
+    }
+}
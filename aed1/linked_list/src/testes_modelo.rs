@@ -0,0 +1,214 @@
+//  Testes baseados em modelo: sequências aleatórias de operações são
+//  aplicadas à LinkedList e a um Vec (o "oráculo"), e os dois precisam
+//  concordar depois de cada passo.
+//
+//  Não usa dependências externas para poder rodar sob o Miri, que
+//  detecta UB nos ponteiros crus e nodes vazados:
+//      cargo +nightly miri test testes_modelo
+//  Sob o Miri o número de sequências é reduzido, pois ele é bem mais lento.
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::linked_list_simple::LinkedList;
+
+    const SEQUENCIAS: u64 = if cfg!(miri) { 4 } else { 300 };
+    const OPERACOES: usize = if cfg!(miri) { 60 } else { 300 };
+
+    //  Valores em um intervalo pequeno geram repetições na lista
+    const VALOR_MAX: u64 = 16;
+
+    /// Gerador xorshift64, determinístico para que falhas sejam reproduzíveis
+    struct Rng(u64);
+
+    impl Rng {
+        fn new(seed: u64) -> Rng {
+            Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+        }
+
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn below(&mut self, n: u64) -> u64 {
+            self.next() % n
+        }
+
+        fn value(&mut self) -> i32 {
+            self.below(VALOR_MAX) as i32
+        }
+    }
+
+    fn assert_same(ll: &LinkedList<i32>, model: &[i32], seed: u64, step: usize) {
+        assert_eq!(ll.len(), model.len(), "seed {} passo {}", seed, step);
+        assert_eq!(ll.list(), model.iter().collect::<Vec<_>>(), "seed {} passo {}", seed, step);
+    }
+
+    //  remove apenas a primeira ocorrência, como LinkedList::remove
+    fn model_remove(model: &mut Vec<i32>, value: i32) -> Option<i32> {
+        let pos = model.iter().position(|&v| v == value)?;
+        return Some(model.remove(pos));
+    }
+
+    fn run_sequence(seed: u64, remove_chance: u64) {
+        let mut rng = Rng::new(seed);
+        let mut ll = LinkedList::new();
+        let mut model = Vec::new();
+
+        for step in 0..OPERACOES {
+            let value = rng.value();
+
+            if rng.below(10) < remove_chance {
+                assert_eq!(ll.remove(value), model_remove(&mut model, value), "seed {} passo {}", seed, step);
+            } else {
+                ll.push(value);
+                model.push(value);
+            }
+
+            assert_same(&ll, &model, seed, step);
+        }
+    }
+
+    //  Operações de fila dupla, que não dependem de remove:
+    //  push_back, push_front, pop_front e pop_back
+    fn run_deque_sequence(seed: u64) {
+        let mut rng = Rng::new(seed);
+        let mut ll = LinkedList::new();
+        let mut model = VecDeque::new();
+
+        for step in 0..OPERACOES {
+            let value = rng.value();
+
+            match rng.below(4) {
+                0 => {
+                    ll.push_back(value);
+                    model.push_back(value);
+                }
+                1 => {
+                    ll.push_front(value);
+                    model.push_front(value);
+                }
+                2 => assert_eq!(ll.pop_front(), model.pop_front(), "seed {} passo {}", seed, step),
+                _ => assert_eq!(ll.pop_back(), model.pop_back(), "seed {} passo {}", seed, step),
+            }
+
+            assert_same(&ll, model.make_contiguous(), seed, step);
+        }
+    }
+
+    #[test]
+    fn random_pushes_match_vec() {
+        for seed in 0..SEQUENCIAS {
+            run_sequence(seed, 0);
+        }
+    }
+
+    #[test]
+    fn random_pushes_and_removes_match_vec() {
+        for seed in 0..SEQUENCIAS {
+            run_sequence(seed, 4);
+        }
+    }
+
+    #[test]
+    fn random_deque_operations_match_vec() {
+        for seed in 0..SEQUENCIAS {
+            run_deque_sequence(seed);
+        }
+    }
+
+    #[test]
+    fn random_sort_matches_vec() {
+        for seed in 0..SEQUENCIAS {
+            let mut rng = Rng::new(seed);
+            let len = rng.below(OPERACOES as u64) as usize;
+
+            let mut ll = LinkedList::new();
+            let mut model = Vec::new();
+            for _ in 0..len {
+                let value = rng.value();
+                ll.push(value);
+                model.push(value);
+            }
+
+            ll.sort();
+            model.sort();
+            assert_same(&ll, &model, seed, len);
+
+            ll.reverse();
+            model.reverse();
+            assert_same(&ll, &model, seed, len);
+        }
+    }
+
+    #[test]
+    fn random_merge_matches_vec() {
+        for seed in 0..SEQUENCIAS {
+            let mut rng = Rng::new(seed);
+            let mut model = Vec::new();
+            let mut lists = [LinkedList::new(), LinkedList::new()];
+
+            for _ in 0..rng.below(OPERACOES as u64) {
+                let value = rng.value();
+                lists[rng.below(2) as usize].push(value);
+                model.push(value);
+            }
+
+            let [mut ll, mut other] = lists;
+            ll.sort();
+            other.sort();
+            ll.merge_sorted(other);
+            model.sort();
+            assert_same(&ll, &model, seed, 0);
+        }
+    }
+
+    //  Operações por índice e por predicado
+    #[test]
+    fn random_indexed_operations_match_vec() {
+        for seed in 0..SEQUENCIAS {
+            let mut rng = Rng::new(seed);
+            let mut ll = LinkedList::new();
+            let mut model = Vec::new();
+
+            for step in 0..OPERACOES {
+                let value = rng.value();
+                let i = rng.below(model.len() as u64 + 2) as usize;
+
+                match rng.below(8) {
+                    0..=2 => {
+                        let i = i.min(model.len());
+                        ll.insert(i, value);
+                        model.insert(i, value);
+                    }
+                    3 | 4 => {
+                        let removed = if i < model.len() { Some(model.remove(i)) } else { None };
+                        assert_eq!(ll.remove_at(i), removed, "seed {} passo {}", seed, step);
+                    }
+                    5 => {
+                        let count = model.iter().filter(|&&v| v == value).count();
+                        model.retain(|&v| v != value);
+                        assert_eq!(ll.remove_all(&value), count, "seed {} passo {}", seed, step);
+                    }
+                    6 => {
+                        ll.retain(|v| v % 3 != value % 3);
+                        model.retain(|v| v % 3 != value % 3);
+                    }
+                    _ => {
+                        assert_eq!(ll.get(i), model.get(i), "seed {} passo {}", seed, step);
+                        assert_eq!(ll.position(|&v| v == value), model.iter().position(|&v| v == value));
+                        assert_eq!(ll.contains(&value), model.contains(&value));
+                    }
+                }
+
+                assert_same(&ll, &model, seed, step);
+                //  push_back usa o tail, então ele também precisa estar certo
+                assert_eq!(ll.get(model.len().wrapping_sub(1)), model.last(), "seed {} passo {}", seed, step);
+            }
+        }
+    }
+}
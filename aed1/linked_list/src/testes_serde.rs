@@ -0,0 +1,42 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use crate::linked_list_simple::LinkedList;
+
+    #[test]
+    fn serialize_preserves_order() {
+        let mut ll = LinkedList::new();
+        ll.push(3);
+        ll.push(1);
+        ll.push(2);
+
+        assert_eq!(serde_json::to_string(&ll).unwrap(), "[3,1,2]");
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut ll = LinkedList::new();
+        for name in ["manteiga", "pao", "arroz", "pao"] {
+            ll.push(name.to_string());
+        }
+
+        let json = serde_json::to_string(&ll).unwrap();
+        let loaded: LinkedList<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded.len(), 4);
+        assert_eq!(loaded.list(), ll.list());
+    }
+
+    #[test]
+    fn round_trip_empty() {
+        let ll: LinkedList<i32> = LinkedList::new();
+        let json = serde_json::to_string(&ll).unwrap();
+
+        assert_eq!(json, "[]");
+        assert_eq!(serde_json::from_str::<LinkedList<i32>>(&json).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn deserialize_rejects_non_sequence() {
+        assert!(serde_json::from_str::<LinkedList<i32>>("3").is_err());
+    }
+}
@@ -1,110 +1,639 @@
  #[cfg(test)]
     mod tests {
-        // use crate::linked_list::LinkedList;
+        use std::cell::Cell;
+
         use crate::linked_list_simple::LinkedList;
 
         #[test] 
-        fn new() {
-            let ll: LinkedList<i32> = LinkedList::new();
+        fn push_back_and_front() {
+            let expected = vec![&3, &1, &2, &4];
+            let mut ll: LinkedList<i32> = LinkedList::new();
+            ll.push_back(1);
+            ll.push_back(2);
+            ll.push_front(3);
+            ll.push_back(4);
 
+            assert_eq!(ll.len(), 4);
+            assert_eq!(ll.list(), expected);
+        }
+
+        #[test] 
+        fn push_front_empty() {
+            let mut ll: LinkedList<i32> = LinkedList::new();
+            ll.push_front(1);
+            ll.push_back(2);
+
+            assert_eq!(ll.len(), 2);
+            assert_eq!(ll.list(), vec![&1, &2]);
+        }
+
+        #[test] 
+        fn pop_front() {
+            let mut ll: LinkedList<i32> = LinkedList::new();
+            ll.push(1);
+            ll.push(2);
+
+            assert_eq!(ll.pop_front(), Some(1));
+            assert_eq!(ll.len(), 1);
+            assert_eq!(ll.pop_front(), Some(2));
+            assert_eq!(ll.len(), 0);
+            assert_eq!(ll.pop_front(), None);
             assert_eq!(ll.len(), 0);
         }
 
         #[test] 
-        fn push_first() {
-            let expected = vec![&1];
+        fn pop_back() {
             let mut ll: LinkedList<i32> = LinkedList::new();
             ll.push(1);
+            ll.push(2);
+            ll.push(3);
+
+            assert_eq!(ll.pop_back(), Some(3));
+            assert_eq!(ll.len(), 2);
+            assert_eq!(ll.list(), vec![&1, &2]);
+            assert_eq!(ll.pop_back(), Some(2));
+            assert_eq!(ll.pop_back(), Some(1));
+            assert_eq!(ll.len(), 0);
+            assert_eq!(ll.pop_back(), None);
+            assert!(ll.is_empty());
+        }
+
+        #[test] 
+        fn push_after_emptied() {
+            //  o tail precisa ser esquecido quando a lista esvazia
+            let mut ll: LinkedList<i32> = LinkedList::new();
+            ll.push(1);
+            ll.pop_back();
+            ll.push(2);
+            ll.push(3);
+            ll.pop_front();
+            ll.pop_front();
+            ll.push_back(4);
 
             assert_eq!(ll.len(), 1);
-            assert_eq!(ll.list(), expected);
+            assert_eq!(ll.list(), vec![&4]);
+        }
+
+        #[test] 
+        fn pop_owned_values() {
+            let mut ll: LinkedList<String> = LinkedList::new();
+            ll.push_back("b".to_string());
+            ll.push_front("a".to_string());
+
+            let first: String = ll.pop_front().unwrap();
+            let last: String = ll.pop_back().unwrap();
+            assert_eq!((first.as_str(), last.as_str()), ("a", "b"));
+        }
+
+        #[test] 
+        fn remove_head_keeps_rest() {
+            let mut ll: LinkedList<i32> = LinkedList::new();
+            ll.push(1);
+            ll.push(2);
+            ll.push(3);
+
+            ll.remove(1);
+
+            assert_eq!(ll.len(), 2);
+            assert_eq!(ll.list(), vec![&2, &3]);
         }
 
         #[test] 
-        fn push_many() {
-            let expected = vec![&1, &2, &3, &4];
+        fn push_after_remove_last() {
+            //  remover o último precisa mover o tail para o penúltimo
             let mut ll: LinkedList<i32> = LinkedList::new();
             ll.push(1);
             ll.push(2);
+
+            ll.remove(2);
             ll.push(3);
-            ll.push(4);
+
+            assert_eq!(ll.len(), 2);
+            assert_eq!(ll.list(), vec![&1, &3]);
+        }
+
+        //  Conta quantas vezes drop foi chamado, para verificar que cada
+        //  valor é desalocado exatamente uma vez
+        #[derive(PartialEq)]
+        struct Contado<'a>(i32, &'a Cell<usize>);
+
+        impl Drop for Contado<'_> {
+            fn drop(&mut self) {
+                self.1.set(self.1.get() + 1);
+            }
+        }
+
+        #[test] 
+        fn remove_drops_value() {
+            let drops = Cell::new(0);
+            let mut ll = LinkedList::new();
+            for i in 0..4 {
+                ll.push(Contado(i, &drops));
+            }
+
+            //  o valor usado na busca também é descartado
+            ll.remove(Contado(2, &drops));
+            assert_eq!(drops.get(), 2);
+            ll.remove(Contado(0, &drops));
+            assert_eq!(drops.get(), 4);
+            ll.remove(Contado(9, &drops));
+            assert_eq!(drops.get(), 5);
+            assert_eq!(ll.len(), 2);
+        }
+
+        #[test] 
+        fn drop_frees_every_node() {
+            let drops = Cell::new(0);
+            let mut ll = LinkedList::new();
+            for i in 0..5 {
+                ll.push(Contado(i, &drops));
+            }
+            ll.pop_front();
+            assert_eq!(drops.get(), 1);
+
+            drop(ll);
+            assert_eq!(drops.get(), 5);
+        }
+
+        #[test] 
+        fn drop_long_list() {
+            //  um Drop recursivo estouraria a pilha aqui
+            let mut ll = LinkedList::new();
+            for i in 0..if cfg!(miri) { 1_000 } else { 200_000 } {
+                ll.push_front(i);
+            }
+            drop(ll);
+        }
+
+        fn from_slice(values: &[i32]) -> LinkedList<i32> {
+            let mut ll = LinkedList::new();
+            for &v in values {
+                ll.push(v);
+            }
+            return ll;
+        }
+
+        #[test] 
+        fn reverse() {
+            let mut ll = from_slice(&[1, 2, 3, 4]);
+            ll.reverse();
 
             assert_eq!(ll.len(), 4);
-            assert_eq!(ll.list(), expected);
+            assert_eq!(ll.list(), vec![&4, &3, &2, &1]);
+
+            //  o tail também precisa ser atualizado
+            ll.push(0);
+            assert_eq!(ll.pop_back(), Some(0));
+            assert_eq!(ll.pop_back(), Some(1));
+        }
+
+        #[test] 
+        fn reverse_small() {
+            let mut ll = from_slice(&[]);
+            ll.reverse();
+            assert!(ll.is_empty());
+
+            let mut ll = from_slice(&[1]);
+            ll.reverse();
+            ll.push(2);
+            assert_eq!(ll.list(), vec![&1, &2]);
+        }
+
+        #[test] 
+        fn merge_sorted() {
+            let mut ll = from_slice(&[1, 4, 6, 9]);
+            ll.merge_sorted(from_slice(&[2, 3, 7, 10, 11]));
+
+            assert_eq!(ll.len(), 9);
+            assert_eq!(ll.list(), vec![&1, &2, &3, &4, &6, &7, &9, &10, &11]);
+            ll.push(12);
+            assert_eq!(ll.pop_back(), Some(12));
+            assert_eq!(ll.pop_back(), Some(11));
+        }
+
+        #[test] 
+        fn merge_sorted_with_empty() {
+            let mut ll = from_slice(&[]);
+            ll.merge_sorted(from_slice(&[1, 2]));
+            assert_eq!(ll.list(), vec![&1, &2]);
+
+            ll.merge_sorted(from_slice(&[]));
+            assert_eq!(ll.len(), 2);
+            ll.push(3);
+            assert_eq!(ll.list(), vec![&1, &2, &3]);
+        }
+
+        //  Comparado só pela chave, para verificar a estabilidade
+        #[derive(Debug)]
+        struct Par(i32, &'static str);
+
+        impl PartialEq for Par {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl PartialOrd for Par {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                self.0.partial_cmp(&other.0)
+            }
+        }
+
+        fn labels(ll: &LinkedList<Par>) -> Vec<&'static str> {
+            ll.list().iter().map(|p| p.1).collect()
+        }
+
+        #[test] 
+        fn merge_sorted_is_stable() {
+            let mut ll = LinkedList::new();
+            ll.push(Par(1, "a1"));
+            ll.push(Par(2, "a2"));
+            let mut other = LinkedList::new();
+            other.push(Par(1, "b1"));
+            other.push(Par(2, "b2"));
+
+            ll.merge_sorted(other);
+            assert_eq!(labels(&ll), vec!["a1", "b1", "a2", "b2"]);
+        }
+
+        #[test] 
+        fn sort() {
+            let mut ll = from_slice(&[5, 1, 4, 2, 8, 0, 2]);
+            ll.sort();
+
+            assert_eq!(ll.len(), 7);
+            assert_eq!(ll.list(), vec![&0, &1, &2, &2, &4, &5, &8]);
+            ll.push(9);
+            assert_eq!(ll.pop_back(), Some(9));
+            assert_eq!(ll.pop_back(), Some(8));
+
+            let mut ll = from_slice(&[]);
+            ll.sort();
+            assert!(ll.is_empty());
+        }
+
+        #[test] 
+        fn sort_is_stable() {
+            let mut ll = LinkedList::new();
+            for par in [Par(1, "c1"), Par(0, "a1"), Par(1, "c2"), Par(0, "a2"), Par(1, "c3"), Par(0, "a3")] {
+                ll.push(par);
+            }
+            ll.sort();
+
+            assert_eq!(labels(&ll), vec!["a1", "a2", "a3", "c1", "c2", "c3"]);
+        }
+
+        #[test] 
+        fn iter() {
+            let ll = from_slice(&[1, 2, 3]);
+
+            let mut iter = ll.iter();
+            assert_eq!(iter.len(), 3);
+            assert_eq!(iter.next(), Some(&1));
+            assert_eq!(iter.len(), 2);
+            assert_eq!(iter.copied().collect::<Vec<_>>(), vec![2, 3]);
+
+            //  compõe com os adaptadores da std
+            let total: i32 = ll.iter().filter(|v| *v % 2 == 1).sum();
+            assert_eq!(total, 4);
+            assert_eq!(from_slice(&[]).iter().next(), None);
+        }
+
+        #[test] 
+        fn iter_mut() {
+            let mut ll = from_slice(&[1, 2, 3]);
+            for value in ll.iter_mut() {
+                *value *= 10;
+            }
+            for value in &mut ll {
+                *value += 1;
+            }
+
+            assert_eq!(ll.list(), vec![&11, &21, &31]);
+        }
+
+        #[test] 
+        fn into_iter() {
+            let ll: LinkedList<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+
+            let mut refs = Vec::new();
+            for value in &ll {
+                refs.push(value.as_str());
+            }
+            assert_eq!(refs, vec!["a", "b", "c"]);
+
+            let mut owned = ll.into_iter();
+            assert_eq!(owned.len(), 3);
+            assert_eq!(owned.next(), Some("a".to_string()));
+            //  o resto é desalocado junto com o iterador
+        }
+
+        #[test] 
+        fn from_iter_and_extend() {
+            let mut ll: LinkedList<i32> = (1..=3).collect();
+            ll.extend(vec![4, 5]);
+            ll.extend(std::iter::empty());
+
+            ll.push(6);
+
+            assert_eq!(ll.len(), 6);
+            assert_eq!(ll.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+        }
+
+        #[test] 
+        fn remove_returns_value() {
+            let mut ll = LinkedList::new();
+            ll.push(Par(1, "a"));
+            ll.push(Par(2, "b"));
+
+            //  devolve o valor que estava na lista, e não o usado na busca
+            assert_eq!(ll.remove(Par(2, "x")).map(|p| p.1), Some("b"));
+            assert_eq!(ll.remove(Par(2, "x")), None);
+            assert_eq!(ll.len(), 1);
+        }
+
+        #[test] 
+        fn get() {
+            let mut ll = from_slice(&[1, 2, 3]);
+
+            assert_eq!((ll.get(0), ll.get(2), ll.get(3)), (Some(&1), Some(&3), None));
+            *ll.get_mut(1).unwrap() = 20;
+            assert_eq!(ll.get_mut(5), None);
+            assert_eq!(ll.list(), vec![&1, &20, &3]);
+        }
+
+        #[test] 
+        fn insert() {
+            let mut ll = from_slice(&[2, 4]);
+            ll.insert(0, 1);
+            ll.insert(2, 3);
+            ll.insert(4, 5);
+
+            assert_eq!(ll.len(), 5);
+            assert_eq!(ll.list(), vec![&1, &2, &3, &4, &5]);
+            assert_eq!(ll.pop_back(), Some(5));
+        }
+
+        #[test] 
+        #[should_panic(expected = "índice 3 fora da lista de tamanho 2")]
+        fn insert_out_of_bounds() {
+            let mut ll = from_slice(&[1, 2]);
+            ll.insert(3, 0);
+        }
+
+        #[test] 
+        fn remove_at() {
+            let mut ll = from_slice(&[1, 2, 3, 4]);
+
+            assert_eq!(ll.remove_at(4), None);
+            assert_eq!(ll.remove_at(3), Some(4));
+            assert_eq!(ll.remove_at(1), Some(2));
+            assert_eq!(ll.remove_at(0), Some(1));
+            assert_eq!(ll.list(), vec![&3]);
+
+            //  o tail continua certo depois de remover o último
+            ll.push(5);
+            assert_eq!(ll.list(), vec![&3, &5]);
+        }
+
+        #[test] 
+        fn contains_find_position() {
+            let ll = from_slice(&[3, 8, 5, 8]);
+
+            assert!(ll.contains(&5));
+            assert!(!ll.contains(&1));
+            assert_eq!(ll.find(|v| v % 2 == 0), Some(&8));
+            assert_eq!(ll.find(|v| *v > 10), None);
+            assert_eq!(ll.position(|v| *v == 8), Some(1));
+            assert_eq!(ll.position(|v| *v == 9), None);
+        }
+
+        #[test] 
+        fn retain() {
+            let mut ll = from_slice(&[1, 2, 3, 4, 5, 6]);
+            ll.retain(|v| v % 2 == 1);
+
+            assert_eq!(ll.len(), 3);
+            assert_eq!(ll.list(), vec![&1, &3, &5]);
+            ll.push(7);
+            assert_eq!(ll.list(), vec![&1, &3, &5, &7]);
+
+            ll.retain(|_| false);
+            assert!(ll.is_empty());
+            ll.push(8);
+            assert_eq!(ll.list(), vec![&8]);
         }
 
-        // #[test] 
-        // fn remove_head() {
-        //     let expected: Vec<&i32> = Vec::new();
-        //     let mut ll: LinkedList<i32> = LinkedList::new();
-        //     ll.push(1);
-
-        //     ll.remove(1);
-
-        //     assert_eq!(ll.len(), 0);
-        //     assert_eq!(ll.list(), expected);
-        // }
-
-        // #[test] 
-        // fn remove_last() {
-        //     let expected: Vec<&i32> = vec![&1, &2, &3];
-        //     let mut ll: LinkedList<i32> = LinkedList::new();
-        //     ll.push(1);
-        //     ll.push(2);
-        //     ll.push(3);
-        //     ll.push(4);
-
-        //     ll.remove(4);
-
-        //     assert_eq!(ll.len(), 3);
-        //     assert_eq!(ll.list(), expected);
-        // }
-
-        // #[test] 
-        // fn remove_midle() {
-        //     let expected: Vec<&i32> = vec![&1, &2, &4];
-        //     let mut ll: LinkedList<i32> = LinkedList::new();
-        //     ll.push(1);
-        //     ll.push(2);
-        //     ll.push(3);
-        //     ll.push(4);
-
-        //     ll.remove(3);
-
-        //     assert_eq!(ll.len(), 3);
-        //     assert_eq!(ll.list(), expected);
-        // }
-
-        // #[test] 
-        // fn remove_many() {
-        //     let expected: Vec<&i32> = vec![&2];
-        //     let mut ll: LinkedList<i32> = LinkedList::new();
-        //     ll.push(1);
-        //     ll.push(2);
-        //     ll.push(3);
-        //     ll.push(4);
-
-        //     ll.remove(3);
-        //     ll.remove(1);
-        //     ll.remove(4);
-
-        //     assert_eq!(ll.len(), 1);
-        //     assert_eq!(ll.list(), expected);
-        // }
-
-        // #[test] 
-        // fn remove_not_found() {
-        //     let expected: Vec<&i32> = vec![&1, &2, &3, &4];
-        //     let mut ll: LinkedList<i32> = LinkedList::new();
-        //     ll.push(1);
-        //     ll.push(2);
-        //     ll.push(3);
-        //     ll.push(4);
-
-        //     ll.remove(5);
-
-        //     assert_eq!(ll.len(), 4);
-        //     assert_eq!(ll.list(), expected);
-        // }
-
-    }
\ No newline at end of file
+        #[test] 
+        fn remove_all() {
+            let mut ll = from_slice(&[2, 1, 2, 3, 2]);
+
+            assert_eq!(ll.remove_all(&2), 3);
+            assert_eq!(ll.remove_all(&9), 0);
+            assert_eq!(ll.list(), vec![&1, &3]);
+            assert_eq!(ll.pop_back(), Some(3));
+        }
+
+        #[test] 
+        fn stores_closures() {
+            //  closures não implementam PartialEq, e podem ser guardadas mesmo assim
+            let mut ll: LinkedList<Box<dyn Fn(i32) -> i32>> = LinkedList::new();
+            ll.push(Box::new(|x| x + 1));
+            ll.push_front(Box::new(|x| x * 2));
+            ll.push(Box::new(|x| x - 3));
+
+            let result = ll.iter().fold(5, |acc, f| f(acc));
+            assert_eq!(result, 8);
+
+            let f = ll.remove_at(1).unwrap();
+            assert_eq!(f(1), 2);
+            assert_eq!(ll.len(), 2);
+        }
+
+        #[test] 
+        fn stores_non_comparable_values() {
+            struct Handle(u32);
+
+            let mut ll: LinkedList<Handle> = (0..4).map(Handle).collect();
+            ll.retain(|h| h.0 % 2 == 0);
+            ll.reverse();
+            assert_eq!(ll.position(|h| h.0 == 0), Some(1));
+
+            let mut boxed: crate::BoxedLinkedList<Handle> = Default::default();
+            boxed.push(Handle(1));
+            boxed.push(Handle(2));
+            assert_eq!(boxed.pop_front().map(|h| h.0), Some(1));
+            assert_eq!(boxed.len(), 1);
+        }
+
+    }
+
+//  Testes da API comum (o trait List), escritos uma vez e
+//  repetidos para cada implementação por testes_lista!
+#[cfg(test)]
+mod generic {
+    use crate::List;
+
+    fn new<L: List<i32>>() {
+        let ll = L::default();
+
+        assert_eq!(ll.len(), 0);
+    }
+
+    fn push_first<L: List<i32>>() {
+        let expected = vec![&1];
+        let mut ll = L::default();
+        ll.push(1);
+
+        assert_eq!(ll.len(), 1);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn push_many<L: List<i32>>() {
+        let expected = vec![&1, &2, &3, &4];
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+        ll.push(3);
+        ll.push(4);
+
+        assert_eq!(ll.len(), 4);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_head<L: List<i32>>() {
+        let expected: Vec<&i32> = Vec::new();
+        let mut ll = L::default();
+        ll.push(1);
+
+        ll.remove(1);
+
+        assert_eq!(ll.len(), 0);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_last<L: List<i32>>() {
+        let expected: Vec<&i32> = vec![&1, &2, &3];
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+        ll.push(3);
+        ll.push(4);
+
+        ll.remove(4);
+
+        assert_eq!(ll.len(), 3);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_midle<L: List<i32>>() {
+        let expected: Vec<&i32> = vec![&1, &2, &4];
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+        ll.push(3);
+        ll.push(4);
+
+        ll.remove(3);
+
+        assert_eq!(ll.len(), 3);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_many<L: List<i32>>() {
+        let expected: Vec<&i32> = vec![&2];
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+        ll.push(3);
+        ll.push(4);
+
+        ll.remove(3);
+        ll.remove(1);
+        ll.remove(4);
+
+        assert_eq!(ll.len(), 1);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_not_found<L: List<i32>>() {
+        let expected: Vec<&i32> = vec![&1, &2, &3, &4];
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+        ll.push(3);
+        ll.push(4);
+
+        ll.remove(5);
+
+        assert_eq!(ll.len(), 4);
+        assert_eq!(ll.list(), expected);
+    }
+
+    fn remove_returns_value<L: List<i32>>() {
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+
+        assert_eq!(ll.remove(2), Some(2));
+        assert_eq!(ll.remove(2), None);
+        assert_eq!(ll.len(), 1);
+    }
+
+    fn pop_front<L: List<i32>>() {
+        let mut ll = L::default();
+        ll.push(1);
+        ll.push(2);
+
+        assert_eq!(ll.pop_front(), Some(1));
+        assert_eq!(ll.pop_front(), Some(2));
+        assert_eq!(ll.pop_front(), None);
+        assert!(ll.is_empty());
+
+        ll.push(3);
+        assert_eq!(ll.list(), vec![&3]);
+    }
+
+    fn contains<L: List<i32>>() {
+        let mut ll = L::default();
+        assert!(!ll.contains(&1));
+
+        ll.push(1);
+        ll.push(2);
+        assert!(ll.contains(&2));
+        assert!(!ll.contains(&3));
+    }
+
+    fn drop_long_list<L: List<i32>>() {
+        //  o push da BoxedLinkedList percorre a lista, então ela não pode ser tão longa
+        let mut ll = L::default();
+        for i in 0..if cfg!(miri) { 300 } else { 20_000 } {
+            ll.push(i);
+        }
+        drop(ll);
+    }
+
+    macro_rules! testes_lista {
+        ($nome:ident: $tipo:ty) => {
+            mod $nome {
+                #[test] fn new() { super::new::<$tipo>() }
+                #[test] fn push_first() { super::push_first::<$tipo>() }
+                #[test] fn push_many() { super::push_many::<$tipo>() }
+                #[test] fn remove_head() { super::remove_head::<$tipo>() }
+                #[test] fn remove_last() { super::remove_last::<$tipo>() }
+                #[test] fn remove_midle() { super::remove_midle::<$tipo>() }
+                #[test] fn remove_many() { super::remove_many::<$tipo>() }
+                #[test] fn remove_not_found() { super::remove_not_found::<$tipo>() }
+                #[test] fn remove_returns_value() { super::remove_returns_value::<$tipo>() }
+                #[test] fn pop_front() { super::pop_front::<$tipo>() }
+                #[test] fn contains() { super::contains::<$tipo>() }
+                #[test] fn drop_long_list() { super::drop_long_list::<$tipo>() }
+            }
+        };
+    }
+
+    testes_lista!(linked_list: crate::LinkedList<i32>);
+    testes_lista!(boxed_linked_list: crate::BoxedLinkedList<i32>);
+    testes_lista!(doubly_linked_list: crate::DoublyLinkedList<i32>);
+    testes_lista!(circular_list: crate::CircularList<i32>);
+}
\ No newline at end of file